@@ -0,0 +1,3 @@
+# Fonksiyonlar host tarafından üretiliyor (oryxis.skills.memorylib).
+# Bu dosya `import memorylib` kodları da çalışsın diye duruyor.
+from oryxis.skills.memorylib import *  # noqa: F401,F403
@@ -0,0 +1,100 @@
+# Web: tarayıcıda açma, sayfa metni / JSON çekme, DuckDuckGo araması.
+# Sadece standart kütüphane; `import weblib` ile kullanılır.
+import json
+import re
+import urllib.parse
+import urllib.request
+import webbrowser
+from html.parser import HTMLParser
+
+_USER_AGENT = 'Mozilla/5.0 (compatible; Oryxis/1.0)'
+_TIMEOUT = 15
+_SKIP_TAGS = {'script', 'style', 'noscript', 'svg', 'head'}
+
+
+def _get(url, headers=None):
+    request = urllib.request.Request(url, headers={'User-Agent': _USER_AGENT, **(headers or {})})
+    with urllib.request.urlopen(request, timeout=_TIMEOUT) as response:
+        charset = response.headers.get_content_charset() or 'utf-8'
+        return response.read().decode(charset, errors='replace')
+
+
+class _TextParser(HTMLParser):
+    def __init__(self):
+        super().__init__()
+        self.parts = []
+        self._skip = 0
+
+    def handle_starttag(self, tag, attrs):
+        if tag in _SKIP_TAGS:
+            self._skip += 1
+        elif tag in ('p', 'br', 'div', 'li', 'tr', 'h1', 'h2', 'h3', 'h4'):
+            self.parts.append('\n')
+
+    def handle_endtag(self, tag):
+        if tag in _SKIP_TAGS and self._skip:
+            self._skip -= 1
+
+    def handle_data(self, data):
+        if not self._skip:
+            self.parts.append(data)
+
+
+def open_url(url):
+    """URL'yi varsayılan tarayıcıda açar."""
+    return webbrowser.open(url)
+
+
+def fetch_text(url):
+    """Sayfanın HTML'siz düz metni."""
+    parser = _TextParser()
+    parser.feed(_get(url))
+    text = ''.join(parser.parts)
+    lines = (re.sub(r'[ \t\r\f\v]+', ' ', line).strip() for line in text.splitlines())
+    return '\n'.join(line for line in lines if line)
+
+
+def fetch_json(url, headers=None):
+    """API'den JSON çekip parse eder."""
+    return json.loads(_get(url, {'Accept': 'application/json', **(headers or {})}))
+
+
+class _ResultParser(HTMLParser):
+    def __init__(self):
+        super().__init__()
+        self.results = []
+        self._current = None
+
+    def handle_starttag(self, tag, attrs):
+        attrs = dict(attrs)
+        if tag == 'a' and 'result__a' in (attrs.get('class') or ''):
+            self._current = {'title': '', 'url': _unwrap(attrs.get('href', ''))}
+
+    def handle_endtag(self, tag):
+        if tag == 'a' and self._current is not None:
+            self._current['title'] = self._current['title'].strip()
+            self.results.append(self._current)
+            self._current = None
+
+    def handle_data(self, data):
+        if self._current is not None:
+            self._current['title'] += data
+
+
+def _unwrap(href):
+    # DuckDuckGo linkleri //duckduckgo.com/l/?uddg=<gerçek url> şeklinde
+    query = urllib.parse.urlparse(href).query
+    target = urllib.parse.parse_qs(query).get('uddg')
+    return target[0] if target else href
+
+
+def search_web(query, max_results=5):
+    """DuckDuckGo'da arar, ilk `max_results` sonucun başlık ve URL'si."""
+    url = 'https://html.duckduckgo.com/html/?' + urllib.parse.urlencode({'q': query})
+    parser = _ResultParser()
+    parser.feed(_get(url))
+    return parser.results[:max_results]
+
+
+# Eski kodlar `weblib.search(...)` çağırıyor
+search = search_web
@@ -1,8 +1,25 @@
 // YENİ DOSYA - tüm API modüllerindeki execute mantığını tek yere toplar
 
-use crate::script::{fix_json_multiline_strings, ScriptResponse, ActionType};
+use crate::script::{fix_json_multiline_strings, ScriptResponse, ActionType, Language};
 use crate::executer::handle_general_execute;
+use crate::shell_executer::handle_shell_execute;
+use crate::js_executer::handle_js_execute;
+use crate::lua_executer::handle_lua_execute;
+use crate::artifacts;
+use crate::oneshot;
+use crate::redact;
+use crate::tool_output;
 use crate::tts;
+use regex::Regex;
+use serde_json::json;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const DEFAULT_EXEC_TIMEOUT_SECS: u64 = 30;
+
+static EXEC_TIMEOUT: OnceLock<Duration> = OnceLock::new();
+static EXEC_ENABLED: OnceLock<bool> = OnceLock::new();
+static READ_ARTIFACT: OnceLock<Regex> = OnceLock::new();
 
 pub enum ExecuteResult {
     Output(String),
@@ -10,10 +27,42 @@ pub enum ExecuteResult {
     EndCode,
 }
 
+/// Executer'lar için timeout (saniye). `exec_enabled` false → action'lar çalışmaz (--no-exec).
+pub fn init(exec_timeout_secs: u64, exec_enabled: bool) {
+    let _ = EXEC_TIMEOUT.set(Duration::from_secs(exec_timeout_secs));
+    let _ = EXEC_ENABLED.set(exec_enabled);
+}
+
+pub fn exec_timeout() -> Duration {
+    *EXEC_TIMEOUT.get_or_init(|| Duration::from_secs(DEFAULT_EXEC_TIMEOUT_SECS))
+}
+
+pub fn is_error_output(result: &str) -> bool {
+    ["Python Error:", "Shell Error:", "JavaScript Error:", "Lua Error:"]
+        .iter()
+        .any(|prefix| result.contains(prefix))
+}
+
+/// Kodu action'daki dile göre ilgili executer'a yollar.
+pub async fn execute_code(language: Language, code: String) -> String {
+    match language {
+        Language::Python => match handle_general_execute(code, exec_timeout()).await {
+            Ok(r) => r,
+            Err(e) => format!("Python Error: {}", e),
+        },
+        Language::Shell => handle_shell_execute(code, exec_timeout()).await,
+        Language::Javascript => handle_js_execute(code, exec_timeout()).await,
+        Language::Lua => handle_lua_execute(code, exec_timeout()).await,
+    }
+}
+
 /// Tüm API modülleri println yerine bunu çağırır.
 /// Hem terminale yazar hem TTS'e gönderir.
 pub async fn display_response(content: &str, tts_voice: &str) {
-    println!("\nOryxis: {}", content);
+    // Model bir sırrı tekrar ederse ekrana ve sese gitmesin
+    let content = &redact::redact(content);
+    oneshot::say(&format!("\nOryxis: {}", content));
+    oneshot::record_answer(content);
 
     eprintln!("[DEBUG] display_response called, tts_voice='{}', content_len={}", tts_voice, content.len());
 
@@ -57,29 +106,89 @@ pub async fn process_ai_response(content: &str) -> ExecuteResult {
         }
     };
 
-    if action.action != ActionType::Execute {
-        return ExecuteResult::NoAction;
+    if action.action == ActionType::FastExecute {
+        let result = run_fast_execute(action.code.trim());
+        oneshot::record_action("fast_execute", action.code.trim(), true, !result.contains("\"error\""), &result);
+        return ExecuteResult::Output(result);
     }
 
-    println!("\n╔════════════════════════════════════════╗");
-    println!("║          🚀 EXECUTE                    ║");
-    println!("╠════════════════════════════════════════╣");
-    for line in action.code.lines().take(5) {
-        println!("║  {}", line);
+    oneshot::say("\n╔════════════════════════════════════════╗");
+    oneshot::say(&format!("║          🚀 EXECUTE ({:?})", action.language));
+    oneshot::say("╠════════════════════════════════════════╣");
+    for line in redact::redact(&action.code).lines().take(5) {
+        oneshot::say(&format!("║  {}", line));
     }
-    println!("╚════════════════════════════════════════╝");
+    oneshot::say("╚════════════════════════════════════════╝");
 
-    let result = match handle_general_execute(action.code.trim().to_string()).await {
-        Ok(r) => r,
-        Err(e) => format!("Python Error: {}", e),
-    };
+    let language = format!("{:?}", action.language).to_lowercase();
+    let shown_code = redact::redact(action.code.trim());
+
+    if !EXEC_ENABLED.get().copied().unwrap_or(true) {
+        oneshot::say("⛔ Not run (execution is disabled).");
+        let result = "Action not run: code execution is disabled for this session (--no-exec). Answer without running code, or tell the user what you would run.";
+        oneshot::record_action(&language, &shown_code, false, true, result);
+        return ExecuteResult::Output(result.to_string());
+    }
 
-    let is_error = result.contains("Python Error:");
-    println!("\n╔════════════════════════════════════════╗");
-    println!("║  {}  ║", if is_error { "❌ ERROR         " } else { "✅ RESULT        " });
-    println!("╠════════════════════════════════════════╣");
-    for line in result.lines() { println!("║  {}", line); }
-    println!("╚════════════════════════════════════════╝");
+    if !tool_output::approve(&shown_code) {
+        oneshot::say("⛔ Not run.");
+        let result = "Action not run: the user declined it because an earlier tool output looked like a prompt injection.";
+        oneshot::record_action(&language, &shown_code, false, false, result);
+        return ExecuteResult::Output(result.to_string());
+    }
+
+    let result = execute_code(action.language, action.code.trim().to_string()).await;
+    // Maskeleme artifact'a yazmadan önce: dosyada da, context'te de, kutuda da sır kalmaz
+    let result = artifacts::limit_output(redact::redact(&result));
 
+    let is_error = is_error_output(&result);
+    oneshot::say("\n╔════════════════════════════════════════╗");
+    oneshot::say(&format!("║  {}  ║", if is_error { "❌ ERROR         " } else { "✅ RESULT        " }));
+    oneshot::say("╠════════════════════════════════════════╣");
+    for line in result.lines() { oneshot::say(&format!("║  {}", line)); }
+    oneshot::say("╚════════════════════════════════════════╝");
+
+    oneshot::record_action(&language, &shown_code, true, !is_error, &result);
     ExecuteResult::Output(result)
 }
+
+/// Host tarafında Python'a gitmeden çalışan built-in'ler.
+/// Şimdilik: read_artifact(handle, offset, len)
+pub fn handle_fast_execute(code: &str) -> String {
+    let re = READ_ARTIFACT.get_or_init(|| {
+        Regex::new(r#"^read_artifact\(\s*['"]?(\w+)['"]?\s*(?:,\s*(\d+)\s*)?(?:,\s*(\d+)\s*)?\)$"#).unwrap()
+    });
+
+    // Kod modelden geliyor; tırnak / ters bölü JSON'u bozmasın
+    let Some(caps) = re.captures(code) else {
+        return json!({ "status": "error", "message": format!("Unknown fast_execute call: {}", code) }).to_string();
+    };
+
+    let handle = &caps[1];
+    let offset = caps.get(2).and_then(|m| m.as_str().parse().ok()).unwrap_or(0);
+    let len = caps.get(3).and_then(|m| m.as_str().parse().ok()).unwrap_or(artifacts::DEFAULT_OUTPUT_LIMIT);
+
+    match artifacts::read_artifact(handle, offset, len) {
+        Ok(page) => page,
+        Err(e) => json!({ "status": "error", "message": e.to_string() }).to_string(),
+    }
+}
+
+fn run_fast_execute(code: &str) -> String {
+    oneshot::say("\n╔════════════════════════════════════════╗");
+    oneshot::say("║          ⚡ FAST EXECUTE               ║");
+    oneshot::say("╠════════════════════════════════════════╣");
+    oneshot::say(&format!("║  {}", code));
+    oneshot::say("╚════════════════════════════════════════╝");
+
+    let result = handle_fast_execute(code);
+
+    let is_error = result.contains("\"error\"");
+    oneshot::say("\n╔════════════════════════════════════════╗");
+    oneshot::say(&format!("║  {}  ║", if is_error { "❌ ERROR         " } else { "✅ RESULT        " }));
+    oneshot::say("╠════════════════════════════════════════╣");
+    for line in result.lines() { oneshot::say(&format!("║  {}", line)); }
+    oneshot::say("╚════════════════════════════════════════╝");
+
+    result
+}
@@ -0,0 +1,107 @@
+// Büyük execution çıktılarını context'e yapıştırmak yerine session klasörüne yazar.
+// Model özet (head/tail) + handle alır, kalanını read_artifact ile sayfa sayfa okur.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::errors::OryxisError;
+
+pub const DEFAULT_OUTPUT_LIMIT: usize = 4000;
+
+struct ArtifactStore {
+    dir: PathBuf,
+    limit: usize,
+    next_id: usize,
+}
+
+static STORE: OnceLock<Mutex<ArtifactStore>> = OnceLock::new();
+static SESSION: OnceLock<u64> = OnceLock::new();
+
+/// Bu çalıştırmanın kimliği (başlangıç zamanı, unix saniye); artifact klasörü ve hafıza kayıtları bunu kullanır.
+pub fn session_id() -> String {
+    SESSION
+        .get_or_init(|| SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs())
+        .to_string()
+}
+
+/// Session artifact klasörünü hazırlar. `limit` karakter cinsinden çıktı bütçesi.
+pub fn init(limit: usize) -> Result<(), OryxisError> {
+    let dir = std::env::current_dir()
+        .unwrap_or_default()
+        .join("memory/artifacts")
+        .join(session_id());
+
+    fs::create_dir_all(&dir).map_err(|e| OryxisError::ArtifactError(e.to_string()))?;
+
+    let _ = STORE.set(Mutex::new(ArtifactStore { dir, limit, next_id: 1 }));
+    Ok(())
+}
+
+fn char_slice(s: &str, start: usize, len: usize) -> &str {
+    let begin = s.char_indices().nth(start).map(|(i, _)| i).unwrap_or(s.len());
+    let end = s[begin..].char_indices().nth(len).map(|(i, _)| begin + i).unwrap_or(s.len());
+    &s[begin..end]
+}
+
+/// Bütçeyi aşan çıktıyı dosyaya yazar ve yerine head/tail özetini döndürür.
+/// Bütçe içindeki çıktılar aynen geri döner.
+pub fn limit_output(result: String) -> String {
+    let Some(store) = STORE.get() else { return result };
+    let mut store = store.lock().unwrap();
+
+    let total = result.chars().count();
+    if total <= store.limit {
+        return result;
+    }
+
+    let handle = format!("a{}", store.next_id);
+    let path = store.dir.join(format!("{}.txt", handle));
+    if let Err(e) = fs::write(&path, &result) {
+        eprintln!("[ARTIFACT] write error: {}", e);
+        return char_slice(&result, 0, store.limit).to_string();
+    }
+    store.next_id += 1;
+
+    let head_len = store.limit / 2;
+    let tail_len = store.limit / 4;
+    let head = char_slice(&result, 0, head_len);
+    let tail = char_slice(&result, total - tail_len, tail_len);
+
+    format!(
+        "[Output truncated: {} chars, {} lines — saved as artifact '{}']\n--- head ---\n{}\n--- tail ---\n{}\n[Page through it with fast_execute: read_artifact('{}', offset, len)]",
+        total,
+        result.lines().count(),
+        handle,
+        head,
+        tail,
+        handle
+    )
+}
+
+/// `handle` artifact'ının `offset` karakterinden itibaren `len` karakterini döndürür.
+/// `len` çıktı bütçesiyle sınırlandırılır.
+pub fn read_artifact(handle: &str, offset: usize, len: usize) -> Result<String, OryxisError> {
+    if handle.is_empty() || !handle.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(OryxisError::ArtifactError(format!("invalid handle '{}'", handle)));
+    }
+
+    let store = STORE
+        .get()
+        .ok_or_else(|| OryxisError::ArtifactError("artifact store not initialized".to_string()))?;
+    let (path, limit) = {
+        let store = store.lock().unwrap();
+        (store.dir.join(format!("{}.txt", handle)), store.limit)
+    };
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| OryxisError::ArtifactError(format!("{}: {}", handle, e)))?;
+
+    let total = content.chars().count();
+    let len = len.min(limit);
+    let page = char_slice(&content, offset, len);
+    let end = (offset + page.chars().count()).min(total);
+
+    Ok(format!("[artifact {}: chars {}..{} of {}]\n{}", handle, offset.min(total), end, total, page))
+}
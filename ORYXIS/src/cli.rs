@@ -0,0 +1,203 @@
+// Komut satırı (clap). Argümansız `oryxis` sohbet başlatır; `oryxis run "<görev>"` ve `oryxis -`
+// tek görevi çalıştırıp çıkar (bkz. oneshot). Yönetim komutları commands::run'da.
+//
+// --model / --provider / --prompt / --no-exec, `--set` gibi en üst katmanda ayarları ezer.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::config::{self, Overrides};
+
+#[derive(Parser)]
+#[command(name = "oryxis", version, about = "Oryxis: a local agent that answers and runs code through skills")]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: Global,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Args)]
+pub struct Global {
+    /// Override a setting for this run (repeatable), e.g. --set generation.temperature=0.3
+    #[arg(long = "set", short = 'c', value_name = "KEY=VALUE", value_parser = config::parse_assignment, global = true)]
+    pub set: Vec<(String, String)>,
+
+    /// Model name (provider.model)
+    #[arg(long, global = true)]
+    pub model: Option<String>,
+
+    /// LLM provider (provider.type)
+    #[arg(long, value_enum, ignore_case = true, global = true)]
+    pub provider: Option<Provider>,
+
+    /// System prompt file (generation.system_prompt)
+    #[arg(long, value_name = "FILE", global = true)]
+    pub prompt: Option<String>,
+
+    /// Show the actions the model asks for without running them (executor.enabled = false)
+    #[arg(long, global = true)]
+    pub no_exec: bool,
+
+    /// With `run` / `-`: print a single JSON result on stdout, everything else goes to stderr
+    #[arg(long, global = true)]
+    pub json: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Provider {
+    Llmapi,
+    Gemini,
+    Groq,
+}
+
+impl Global {
+    /// Kısa bayraklar `--set`'ten sonra: ikisi birden verilirse bayrak kazanır
+    pub fn overrides(&self) -> Overrides {
+        let mut overrides = self.set.clone();
+        if let Some(provider) = self.provider {
+            let name = match provider {
+                Provider::Llmapi => "LLMAPI",
+                Provider::Gemini => "GEMINI",
+                Provider::Groq => "GROQ",
+            };
+            overrides.push(("provider.type".to_string(), name.to_string()));
+        }
+        if let Some(model) = &self.model {
+            overrides.push(("provider.model".to_string(), model.clone()));
+        }
+        if let Some(prompt) = &self.prompt {
+            overrides.push(("generation.system_prompt".to_string(), prompt.clone()));
+        }
+        if self.no_exec {
+            overrides.push(("executor.enabled".to_string(), "false".to_string()));
+        }
+        overrides
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Interactive chat (the default)
+    Chat,
+    /// Read a task from stdin and run it like `run` (echo "..." | oryxis -)
+    #[command(name = "-")]
+    Stdin,
+    /// Run one task and exit: 0 done, 1 Oryxis / provider error, 2 the last action failed or was declined
+    Run {
+        /// The task; `-` reads it from stdin
+        #[arg(required = true, num_args = 1.., value_name = "TASK")]
+        task: Vec<String>,
+    },
+    /// Merged settings and where each one comes from
+    Config {
+        #[command(subcommand)]
+        command: Option<ConfigCommand>,
+    },
+    /// Skill Python requirements
+    Deps {
+        #[command(subcommand)]
+        command: DepsCommand,
+    },
+    /// Manage, test and package skills
+    #[command(visible_alias = "skill")]
+    Skills {
+        #[command(subcommand)]
+        command: SkillsCommand,
+    },
+    /// Review memories learned from conversations (same as /memories in chat)
+    Memories,
+    /// Import skills and memories from the prototype's OryxisMemory database (e.g. ../rust_tests/mydb)
+    Import {
+        dir: String,
+        /// Show what would be imported without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Encrypted storage for memory, conversations and API keys
+    Vault {
+        #[command(subcommand)]
+        command: Option<VaultCommand>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Defaults, /etc/oryxis, ~/.config/oryxis, ./oryxis.toml, env and command line, merged
+    Show,
+}
+
+#[derive(Subcommand)]
+pub enum DepsCommand {
+    /// List skill Python requirements that are not installed
+    Check,
+    /// Install missing requirements from the local wheelhouse
+    Install,
+}
+
+#[derive(Subcommand)]
+pub enum SkillsCommand {
+    /// Check skills/*.yaml against the files they reference
+    Validate,
+    /// Rebuild memory/skills_index.json from skills/*.yaml
+    Reindex,
+    /// Run the YAML examples and check their `expect`, per ability
+    Test { skill: Option<String> },
+    /// Create libraries_opensource/<name>, build it and install it into skills/
+    New {
+        name: String,
+        /// Sandboxed WebAssembly skill instead of a native library
+        #[arg(long)]
+        wasm: bool,
+    },
+    /// Rebuild and reinstall a skill crate after editing it
+    Build { name: String },
+    /// Bundle an installed skill into <name>-<version>.oryxskill
+    Pack { name: String, out_dir: Option<String> },
+    /// Install a .oryxskill bundle (or an unpacked one)
+    Install {
+        source: String,
+        /// Replace an installed copy, whatever its version
+        #[arg(long)]
+        force: bool,
+    },
+    /// Upgrade from a bundle, or every installed skill a folder of bundles has newer
+    Upgrade { source: String },
+    /// Remove a skill
+    Uninstall {
+        name: String,
+        /// Remove it even if other skills depend on it
+        #[arg(long)]
+        force: bool,
+    },
+    /// Installed skills, versions and dependencies
+    List,
+    /// Show effective skill permissions
+    Permissions { skill: Option<String> },
+    /// Grant a permission, e.g. fs.write:~/notes, process.spawn:git
+    Grant { skill: String, permission: String },
+    /// Revoke a single item (fs.write:~/notes) or a whole kind (fs.write)
+    Revoke { skill: String, permission: String },
+}
+
+#[derive(Subcommand)]
+pub enum VaultCommand {
+    /// Encrypt memory, conversations and .env API keys (key in the OS keyring, or a passphrase)
+    Init {
+        #[arg(long)]
+        passphrase: bool,
+    },
+    /// Check the vault opens and recover an unclean shutdown
+    Unlock,
+    /// Re-encrypt everything with a new key
+    Rotate {
+        /// Derive the new key from a passphrase
+        #[arg(long, conflicts_with = "keyring")]
+        passphrase: bool,
+        /// Keep the new key in the OS keyring
+        #[arg(long)]
+        keyring: bool,
+    },
+    /// What is encrypted and where the key is
+    Status,
+}
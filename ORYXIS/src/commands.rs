@@ -0,0 +1,73 @@
+// `oryxis <subcommand>` — chat döngüsü dışında çalışan yönetim komutları (tanımları cli.rs'de)
+
+use crate::cli::{Command, ConfigCommand, DepsCommand, SkillsCommand, VaultCommand};
+use crate::config;
+use crate::errors::OryxisError;
+use crate::memory_extract;
+use crate::permissions;
+use crate::prototype_import;
+use crate::skill_bundle;
+use crate::skill_index;
+use crate::skill_manifest;
+use crate::skill_scaffold;
+use crate::skill_test;
+use crate::vault;
+use crate::venv;
+
+pub fn run(command: Command) -> Result<(), OryxisError> {
+    match command {
+        Command::Config { command: None | Some(ConfigCommand::Show) } => config::show(),
+        Command::Deps { command: DepsCommand::Check } => deps_check(),
+        Command::Deps { command: DepsCommand::Install } => venv::install_missing(),
+        Command::Skills { command } => skills(command),
+        Command::Memories => with_vault(memory_extract::review_pending),
+        Command::Import { dir, dry_run } => with_vault(|| prototype_import::run(&dir, dry_run)),
+        Command::Vault { command } => match command.unwrap_or(VaultCommand::Status) {
+            VaultCommand::Init { passphrase } => vault::init(passphrase),
+            VaultCommand::Unlock => vault::unlock(),
+            VaultCommand::Rotate { passphrase: true, .. } => vault::rotate(Some(vault::KeySource::Passphrase)),
+            VaultCommand::Rotate { keyring: true, .. } => vault::rotate(Some(vault::KeySource::OsKeyring)),
+            VaultCommand::Rotate { .. } => vault::rotate(None),
+            VaultCommand::Status => vault::status(),
+        },
+        // main'de karşılanır (chat başlatır)
+        Command::Chat | Command::Run { .. } | Command::Stdin => Ok(()),
+    }
+}
+
+fn skills(command: SkillsCommand) -> Result<(), OryxisError> {
+    match command {
+        SkillsCommand::Validate => skill_manifest::validate_all(),
+        SkillsCommand::Reindex => skill_index::reindex_command(),
+        SkillsCommand::Test { skill } => skill_test::run(skill.as_deref()),
+        SkillsCommand::New { name, wasm } => skill_scaffold::new_skill(&name, wasm),
+        SkillsCommand::Build { name } => skill_scaffold::build(&name),
+        SkillsCommand::Pack { name, out_dir } => skill_bundle::pack(&name, out_dir.as_deref()),
+        SkillsCommand::Install { source, force } => skill_bundle::install(&source, force),
+        SkillsCommand::Upgrade { source } => skill_bundle::upgrade(&source),
+        SkillsCommand::Uninstall { name, force } => skill_bundle::uninstall(&name, force),
+        SkillsCommand::List => skill_bundle::list(),
+        SkillsCommand::Permissions { skill } => permissions::show(skill.as_deref()),
+        SkillsCommand::Grant { skill, permission } => permissions::grant(&skill, &permission),
+        SkillsCommand::Revoke { skill, permission } => permissions::revoke(&skill, &permission),
+    }
+}
+
+/// Hafızaya dokunan komutlar vault açıkken çalışır (kapanınca yeniden şifrelenir)
+fn with_vault(command: impl FnOnce() -> Result<(), OryxisError>) -> Result<(), OryxisError> {
+    let _vault = vault::open()?;
+    command()
+}
+
+fn deps_check() -> Result<(), OryxisError> {
+    let missing = venv::missing_requirements()?;
+    if missing.is_empty() {
+        println!("All skill requirements are installed.");
+        return Ok(());
+    }
+    for (skill, req) in &missing {
+        println!("  {:<16} {}", skill, req);
+    }
+    println!("{} missing — run `oryxis deps install`", missing.len());
+    Ok(())
+}
@@ -0,0 +1,415 @@
+// oryxis.toml: katmanlı ayarlar. Sonraki katman öncekini ezer:
+//
+//   varsayılan → sistem (/etc/oryxis/oryxis.toml) → kullanıcı (~/.config/oryxis/oryxis.toml)
+//   → proje (./oryxis.toml) → env (.env dahil; eski isimler: API_TYPE, LLM_MODEL, TTS, ...)
+//   → komut satırı (`oryxis --set generation.temperature=0.3 ...`, --model, --provider, --prompt, --no-exec)
+//
+// API anahtarı vault'taysa env katmanında oradan gelir. `oryxis config show` birleşmiş hâli
+// ve her değerin nereden geldiğini yazar.
+//
+// `permissions.*` gibi `*` ile biten anahtarlar skill başına açılır (`[permissions.file_lib]`);
+// her skill'in tablosu bir bütün olarak ezilir.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use toml::Value;
+
+use crate::errors::OryxisError;
+use crate::vault;
+
+pub const PROJECT_FILE: &str = "oryxis.toml";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Str,
+    Int,
+    Float,
+    Bool,
+    /// ["a", "b"]
+    List,
+    /// { isim = "değer" }
+    Map,
+    /// { grant = [..], revoke = [..] }
+    Grants,
+}
+
+struct KeyDef {
+    key: &'static str,
+    kind: Kind,
+    /// TOML değeri olarak; None → varsayılan yok
+    default: Option<&'static str>,
+    env: Option<&'static str>,
+    help: &'static str,
+    /// `config show` değeri göstermez
+    secret: bool,
+}
+
+const fn def(key: &'static str, kind: Kind, default: Option<&'static str>, env: Option<&'static str>, help: &'static str) -> KeyDef {
+    KeyDef { key, kind, default, env, help, secret: false }
+}
+
+const KEYS: &[KeyDef] = &[
+    def("provider.type", Kind::Str, None, Some("API_TYPE"), "LLMAPI, GEMINI or GROQ"),
+    def("provider.model", Kind::Str, None, Some("LLM_MODEL"), "model name, depends on the provider"),
+    KeyDef { key: "provider.api_key", kind: Kind::Str, default: None, env: Some("API_KEY"), help: "better kept in .env or the vault", secret: true },
+    def("generation.temperature", Kind::Float, Some("0.7"), Some("TEMPERATURE"), "sampling temperature for chat"),
+    def("generation.top_p", Kind::Float, Some("0.9"), Some("TOP_P"), "nucleus sampling for chat"),
+    def("generation.extract_temperature", Kind::Float, Some("0.2"), None, "temperature for memory extraction requests"),
+    def("generation.system_prompt", Kind::Str, Some("\"prompt.md\""), Some("SYSTEM_PROMPT"), "system prompt file, relative to the project"),
+    def("executor.timeout", Kind::Int, Some("30"), Some("EXEC_TIMEOUT"), "seconds before python / shell / javascript / lua executions are stopped"),
+    def("executor.enabled", Kind::Bool, Some("true"), None, "run the code the model asks for (false / --no-exec: only show it)"),
+    def("executor.output_limit", Kind::Int, Some("4000"), Some("OUTPUT_LIMIT"), "max chars of an execution result sent to the model"),
+    def("skills.context_tokens", Kind::Int, Some("600"), Some("SKILL_CONTEXT_TOKENS"), "token budget for skill docs injected per message (0 disables)"),
+    def("skills.watch", Kind::Bool, Some("true"), Some("SKILL_WATCH"), "reload skills when files in skills/ change"),
+    def("skills.wheelhouse", Kind::Str, Some("\"wheelhouse\""), Some("WHEELHOUSE"), "local wheels for skill requirements"),
+    def("skills.python", Kind::Str, None, Some("PYTHON"), "interpreter used for pip (python3 / python)"),
+    def("memory.context_tokens", Kind::Int, Some("300"), Some("MEMORY_CONTEXT_TOKENS"), "token budget for remembered facts injected per message (0 disables)"),
+    def("memory.extract", Kind::Bool, Some("true"), Some("MEMORY_EXTRACT"), "learn long-term memories from conversations"),
+    def("memory.extract_turns", Kind::Int, Some("10"), Some("MEMORY_EXTRACT_TURNS"), "extract every N turns in the background (0: only on exit)"),
+    def("tts.voice", Kind::Str, Some("\"\""), Some("TTS"), "edge-tts voice, empty disables speech"),
+    def("security.redact", Kind::Bool, Some("true"), Some("REDACT"), "mask secrets in execution results"),
+    def("security.injection_guard", Kind::Bool, Some("true"), Some("INJECTION_GUARD"), "ask before actions that follow a suspected prompt injection"),
+    def("security.redact_patterns", Kind::Map, Some("{}"), Some("REDACT_PATTERNS"), "extra secrets to mask: { kind = \"regex\" }, checked before the built-in ones"),
+    def("security.redact_allow", Kind::List, Some("[]"), Some("REDACT_ALLOW"), "regexes for values that are never masked (whole match)"),
+    def("permissions.*", Kind::Grants, None, None, "per skill: grant = [..], revoke = [..] on top of the manifest (`oryxis skills grant|revoke`)"),
+];
+
+#[derive(Clone)]
+pub enum Source {
+    Default,
+    File(&'static str, PathBuf),
+    Env(&'static str),
+    Vault,
+    Cli,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(layer, path) => write!(f, "{} {}", layer, path.display()),
+            Source::Env(name) => write!(f, "env {}", name),
+            Source::Vault => write!(f, "vault"),
+            Source::Cli => write!(f, "command line"),
+        }
+    }
+}
+
+pub struct Config {
+    values: BTreeMap<String, (Value, Source)>,
+    /// (katman, dosya, var mı)
+    files: Vec<(&'static str, PathBuf, bool)>,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+fn config_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::ConfigError(msg.to_string())
+}
+
+fn system_file() -> PathBuf {
+    if cfg!(windows) {
+        std::env::var_os("PROGRAMDATA").map(PathBuf::from).unwrap_or_else(|| PathBuf::from("C:\\ProgramData")).join("oryxis").join(PROJECT_FILE)
+    } else {
+        PathBuf::from("/etc/oryxis").join(PROJECT_FILE)
+    }
+}
+
+fn user_file() -> Option<PathBuf> {
+    let dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+    };
+    dir.map(|d| d.join("oryxis").join(PROJECT_FILE))
+}
+
+/// `permissions.file_lib` → `permissions.*`
+fn key_def(key: &str) -> Option<&'static KeyDef> {
+    KEYS.iter().find(|k| match k.key.strip_suffix('*') {
+        Some(prefix) => key.strip_prefix(prefix).is_some_and(|name| !name.is_empty() && !name.contains('.')),
+        None => k.key == key,
+    })
+}
+
+/// Sırayla okunan dosya katmanları: (katman, yol)
+pub fn layer_files() -> Vec<(&'static str, PathBuf)> {
+    let mut files = vec![("system", system_file())];
+    files.extend(user_file().map(|f| ("user", f)));
+    files.push(("project", project_file()));
+    files
+}
+
+pub fn project_file() -> PathBuf {
+    std::env::current_dir().unwrap_or_default().join(PROJECT_FILE)
+}
+
+fn kind_name(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Str => "a string",
+        Kind::Int => "an integer",
+        Kind::Float => "a number",
+        Kind::Bool => "true/false",
+        Kind::List => "a list of strings",
+        Kind::Map => "a table of strings",
+        Kind::Grants => "a table of grant / revoke string lists",
+    }
+}
+
+/// Dosyadan gelen değer doğru tipte mi (tamsayı float alanına yazılabilir)
+fn check_type(def: &KeyDef, value: Value) -> Option<Value> {
+    match (def.kind, value) {
+        (Kind::Str, v @ Value::String(_)) | (Kind::Bool, v @ Value::Boolean(_)) | (Kind::Float, v @ Value::Float(_)) => Some(v),
+        (Kind::Int, Value::Integer(i)) if i >= 0 => Some(Value::Integer(i)),
+        (Kind::Float, Value::Integer(i)) => Some(Value::Float(i as f64)),
+        (Kind::List, Value::Array(a)) if a.iter().all(Value::is_str) => Some(Value::Array(a)),
+        (Kind::Map, Value::Table(t)) if t.values().all(Value::is_str) => Some(Value::Table(t)),
+        (Kind::Grants, Value::Table(t)) => {
+            let lists = t.iter().all(|(k, v)| {
+                matches!(k.as_str(), "grant" | "revoke")
+                    && v.as_array().is_some_and(|a| a.iter().all(Value::is_str))
+            });
+            lists.then_some(Value::Table(t))
+        }
+        _ => None,
+    }
+}
+
+/// Satır içi TOML değeri: `["a"]`, `{ a = "b" }`
+fn parse_inline(def: &KeyDef, text: &str) -> Option<Value> {
+    toml::from_str::<toml::Table>(&format!("v = {}", text))
+        .ok()
+        .and_then(|mut t| t.remove("v"))
+        .and_then(|v| check_type(def, v))
+}
+
+/// env / --set metni → değer. Bool'da eski davranış: "0", "false", "no", "off" dışı her şey açık.
+/// Liste TOML dizisi ya da virgülle ayrılmış olabilir; tablolar satır içi TOML'dur.
+fn parse_text(def: &KeyDef, text: &str) -> Option<Value> {
+    let text = text.trim();
+    match def.kind {
+        Kind::Str => Some(Value::String(text.to_string())),
+        Kind::Int => text.parse::<u64>().ok().and_then(|i| i64::try_from(i).ok()).map(Value::Integer),
+        Kind::Float => text.parse::<f64>().ok().map(Value::Float),
+        Kind::Bool => Some(Value::Boolean(!matches!(text.to_ascii_lowercase().as_str(), "0" | "false" | "no" | "off"))),
+        Kind::List if !text.starts_with('[') => Some(Value::Array(
+            text.split(',').map(str::trim).filter(|s| !s.is_empty()).map(|s| Value::String(s.to_string())).collect(),
+        )),
+        // `--set 'permissions.file_lib={ grant = ["fs.read:~"] }'`
+        Kind::List | Kind::Map | Kind::Grants => parse_inline(def, text),
+    }
+}
+
+fn read_file(layer: &'static str, path: &PathBuf, values: &mut BTreeMap<String, (Value, Source)>) -> Result<(), OryxisError> {
+    let raw = std::fs::read_to_string(path).map_err(|e| config_err(format!("{}: {}", path.display(), e)))?;
+    let table: toml::Table = toml::from_str(&raw).map_err(|e| config_err(format!("{}: {}", path.display(), e)))?;
+    for (section, entries) in table {
+        let Value::Table(entries) = entries else {
+            eprintln!("[CONFIG] {}: `{}` should be a [section]", path.display(), section);
+            continue;
+        };
+        for (name, value) in entries {
+            let key = format!("{}.{}", section, name);
+            let Some(def) = key_def(&key) else {
+                eprintln!("[CONFIG] {}: unknown key `{}`", path.display(), key);
+                continue;
+            };
+            let value = check_type(def, value).ok_or_else(|| config_err(format!("{}: `{}` must be {}", path.display(), key, kind_name(def.kind))))?;
+            values.insert(key, (value, Source::File(layer, path.clone())));
+        }
+    }
+    Ok(())
+}
+
+/// (anahtar, değer) — komut satırından (--set, --model, --provider, --prompt, --no-exec)
+pub type Overrides = Vec<(String, String)>;
+
+/// `--set a.b=c` değeri (clap value_parser)
+pub fn parse_assignment(assignment: &str) -> Result<(String, String), String> {
+    let (key, value) = assignment.split_once('=').ok_or_else(|| format!("`{}` is not key=value", assignment))?;
+    Ok((key.trim().to_string(), value.to_string()))
+}
+
+fn build(cli: &[(String, String)]) -> Result<Config, OryxisError> {
+    let mut values = BTreeMap::new();
+    for def in KEYS {
+        if let Some(default) = def.default {
+            let mut table: toml::Table = toml::from_str(&format!("v = {}", default)).map_err(|e| config_err(format!("default of {}: {}", def.key, e)))?;
+            if let Some(value) = table.remove("v") {
+                values.insert(def.key.to_string(), (value, Source::Default));
+            }
+        }
+    }
+
+    let mut seen = Vec::new();
+    for (layer, path) in layer_files() {
+        let exists = path.is_file();
+        if exists {
+            read_file(layer, &path, &mut values)?;
+        }
+        seen.push((layer, path, exists));
+    }
+
+    for def in KEYS {
+        let Some(name) = def.env else { continue };
+        let Ok(text) = std::env::var(name) else { continue };
+        match parse_text(def, &text) {
+            Some(value) => {
+                values.insert(def.key.to_string(), (value, Source::Env(name)));
+            }
+            None => eprintln!("[CONFIG] {}={} ignored: {} must be {}", name, text, def.key, kind_name(def.kind)),
+        }
+    }
+    // vault açıksa anahtar oradan (env'deki eski anahtarı ezer)
+    if let Some(api_key) = vault::secret("API_KEY") {
+        values.insert("provider.api_key".to_string(), (Value::String(api_key), Source::Vault));
+    }
+
+    for (key, text) in cli {
+        let def = key_def(key).ok_or_else(|| config_err(format!("unknown key `{}` (see `oryxis config show`)", key)))?;
+        let value = parse_text(def, text).ok_or_else(|| config_err(format!("--set {}: must be {}", key, kind_name(def.kind))))?;
+        values.insert(key.clone(), (value, Source::Cli));
+    }
+
+    Ok(Config { values, files: seen })
+}
+
+/// Startup'ta bir kez (vault açıldıktan sonra): katmanları birleştirir. `cli`: --set ve kısa bayraklar
+pub fn load(cli: &[(String, String)]) -> Result<&'static Config, OryxisError> {
+    if CONFIG.get().is_none() {
+        let _ = CONFIG.set(build(cli)?);
+    }
+    Ok(get())
+}
+
+/// Birleşmiş ayarlar; load çağrılmadıysa CLI katmanı olmadan yüklenir (hatalıysa varsayılanlar)
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| {
+        build(&[]).unwrap_or_else(|e| {
+            eprintln!("[CONFIG] {}", e);
+            Config { values: BTreeMap::new(), files: Vec::new() }
+        })
+    })
+}
+
+impl Config {
+    fn value(&self, key: &str) -> Option<&Value> {
+        debug_assert!(key_def(key).is_some(), "unregistered config key {}", key);
+        self.values.get(key).map(|(value, _)| value)
+    }
+
+    pub fn str(&self, key: &str) -> Option<String> {
+        self.value(key).and_then(Value::as_str).map(str::to_string)
+    }
+
+    pub fn usize(&self, key: &str) -> usize {
+        self.value(key).and_then(Value::as_integer).unwrap_or(0) as usize
+    }
+
+    pub fn f32(&self, key: &str) -> f32 {
+        self.value(key).and_then(Value::as_float).unwrap_or(0.0) as f32
+    }
+
+    pub fn bool(&self, key: &str) -> bool {
+        self.value(key).and_then(Value::as_bool).unwrap_or(false)
+    }
+
+    pub fn list(&self, key: &str) -> Vec<String> {
+        self.value(key)
+            .and_then(Value::as_array)
+            .map(|a| a.iter().filter_map(Value::as_str).map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn map(&self, key: &str) -> BTreeMap<String, String> {
+        self.value(key)
+            .and_then(Value::as_table)
+            .map(|t| t.iter().filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string()))).collect())
+            .unwrap_or_default()
+    }
+
+    /// `*` anahtarının açıldığı değerler: "permissions" → [("file_lib", {..}), ..]
+    pub fn section(&self, section: &str) -> Vec<(&str, &Value)> {
+        let prefix = format!("{}.", section);
+        self.values
+            .iter()
+            .filter_map(|(key, (value, _))| key.strip_prefix(&prefix).map(|name| (name, value)))
+            .collect()
+    }
+
+    /// Zorunlu ayar; yoksa hangi yollarla verilebileceğini söyler
+    pub fn required(&self, key: &str) -> Result<String, OryxisError> {
+        self.str(key).filter(|v| !v.is_empty()).ok_or_else(|| {
+            let def = key_def(key);
+            let env = def.and_then(|d| d.env).map(|e| format!(", {} in .env", e)).unwrap_or_default();
+            config_err(format!("`{}` is not set (oryxis.toml [{}]{})", key, key.split('.').next().unwrap_or(""), env))
+        })
+    }
+}
+
+/// Sohbet istekleri için üretim parametreleri
+pub struct Generation {
+    pub temperature: f32,
+    pub top_p: f32,
+    pub extract_temperature: f32,
+}
+
+pub fn generation() -> Generation {
+    let config = get();
+    Generation {
+        temperature: config.f32("generation.temperature"),
+        top_p: config.f32("generation.top_p"),
+        extract_temperature: config.f32("generation.extract_temperature"),
+    }
+}
+
+/// Proje dosyasını yorumları koruyarak düzenler (`oryxis skills grant` gibi komutlar için)
+pub fn edit_project(edit: impl FnOnce(&mut toml_edit::DocumentMut)) -> Result<PathBuf, OryxisError> {
+    let path = project_file();
+    let raw = match std::fs::read_to_string(&path) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(config_err(format!("{}: {}", path.display(), e))),
+    };
+    let mut doc: toml_edit::DocumentMut = raw.parse().map_err(|e| config_err(format!("{}: {}", path.display(), e)))?;
+    edit(&mut doc);
+    std::fs::write(&path, doc.to_string()).map_err(|e| config_err(format!("{}: {}", path.display(), e)))?;
+    Ok(path)
+}
+
+/// `oryxis config show`
+pub fn show() -> Result<(), OryxisError> {
+    let config = get();
+    for (layer, path, exists) in &config.files {
+        println!("# {:<8} {} {}", layer, path.display(), if *exists { "" } else { "(not found)" });
+    }
+    let mut section = "";
+    for def in KEYS {
+        let (name, key) = def.key.split_once('.').unwrap_or(("", def.key));
+        if name != section {
+            println!("\n[{}]", name);
+            section = name;
+        }
+        if key == "*" {
+            let entries: Vec<_> = config.values.iter().filter(|(k, _)| key_def(k).is_some_and(|d| std::ptr::eq(d, def))).collect();
+            if entries.is_empty() {
+                println!("{:<40} # not set — {}", "# <skill> =", def.help);
+            }
+            for (full, (value, source)) in entries {
+                let skill = full.strip_prefix(&format!("{}.", name)).unwrap_or(full);
+                println!("{:<40} # {}", format!("{} = {}", skill, value), source);
+            }
+            continue;
+        }
+        let (line, source) = match config.values.get(def.key) {
+            Some((_, source)) if def.secret => (format!("{} = \"****\"", key), source.to_string()),
+            Some((value, source)) => (format!("{} = {}", key, value), source.to_string()),
+            None => (format!("# {} =", key), "not set".to_string()),
+        };
+        println!("{:<40} # {} — {}", line, source, def.help);
+    }
+    Ok(())
+}
@@ -55,5 +55,35 @@ pub enum OryxisError {
 
     #[error("WhatsApp API error: {0}")]
     WhatsAppError(String),
+
+    #[error("Artifact error: {0}")]
+    ArtifactError(String),
+
+    #[error("Python environment error: {0}")]
+    PyEnvError(String),
+
+    #[error("Invalid skill manifest: {0}")]
+    InvalidSkill(String),
+
+    #[error("Native skill error: {0}")]
+    PluginError(String),
+
+    #[error("Skill build failed: {0}")]
+    SkillBuildError(String),
+
+    #[error("Skill bundle error: {0}")]
+    BundleError(String),
+
+    #[error("Prototype import failed: {0}")]
+    ImportError(String),
+
+    #[error("Vault error: {0}")]
+    VaultError(String),
+
+    #[error("Config error: {0}")]
+    ConfigError(String),
+
+    #[error("Run failed: {0}")]
+    RunError(String),
 }
 
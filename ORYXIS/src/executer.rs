@@ -1,20 +1,78 @@
+use pyo3::exceptions::{PySyntaxError, PyTimeoutError};
 use pyo3::prelude::*;
 use pyo3::types::*;
-use std::sync::Once;
+use std::os::raw::c_long;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once};
+use std::time::Duration;
+
+use crate::py_bridge::oryxis;
 
 
 static PYTHON_INIT: Once = Once::new();
 
 pub fn ensure_python_init() {
     PYTHON_INIT.call_once(|| {
+        // `import oryxis` interpreter başlamadan kaydedilmeli
+        pyo3::append_to_inittab!(oryxis);
         pyo3::prepare_freethreaded_python();
+
+        // Skill bağımlılıkları (.oryxis/site-packages) sistem paketlerinden önce gelir
+        Python::with_gil(|py| {
+            if let Err(e) = crate::venv::activate(py) {
+                eprintln!("[VENV] activate error: {}", e);
+            }
+        });
     });
 }
 
-pub async fn handle_general_execute(code: String) -> PyResult<String,> {
+/// Süre dolunca çalışan Python thread'ine TimeoutError fırlatır. Sıradaki bytecode'da
+/// yükselir; tek bir uzun C çağrısının (ör. time.sleep) içini kesemez, dönünce durur.
+struct Watchdog {
+    thread: c_long,
+    done: Arc<AtomicBool>,
+    fired: Arc<AtomicBool>,
+}
+
+impl Watchdog {
+    fn start(py: Python<'_>, timeout: Duration) -> PyResult<Self> {
+        let thread: u64 = py.import("threading")?.call_method0("get_ident")?.extract()?;
+        let thread = thread as c_long;
+        let done = Arc::new(AtomicBool::new(false));
+        let fired = Arc::new(AtomicBool::new(false));
+
+        let (watch_done, watch_fired) = (done.clone(), fired.clone());
+        std::thread::spawn(move || {
+            std::thread::sleep(timeout);
+            // done GIL tutulurken yazılır; GIL bizdeyken false ise kod hâlâ çalışıyor
+            Python::with_gil(|py| {
+                if !watch_done.load(Ordering::SeqCst) {
+                    watch_fired.store(true, Ordering::SeqCst);
+                    let exc = py.get_type::<PyTimeoutError>();
+                    unsafe { pyo3::ffi::PyThreadState_SetAsyncExc(thread, exc.as_ptr()) };
+                }
+            });
+        });
+        Ok(Watchdog { thread, done, fired })
+    }
+
+    /// GIL tutulurken çağrılır; yükselmemiş bir TimeoutError kaldıysa sonraki çalıştırmaya geçmesin
+    fn stop(&self, _py: Python<'_>) -> bool {
+        self.done.store(true, Ordering::SeqCst);
+        unsafe { pyo3::ffi::PyThreadState_SetAsyncExc(self.thread, std::ptr::null_mut()) };
+        self.fired.load(Ordering::SeqCst)
+    }
+}
+
+pub async fn handle_general_execute(code: String, timeout: Duration) -> PyResult<String,> {
     ensure_python_init();
 
     Python::with_gil(|py| {
+        // skills/ değiştiyse oryxis.skills ve wrapper modülleri tazelenir
+        if let Err(e) = crate::skill_watch::apply_python(py) {
+            eprintln!("[SKILLS] python reload error: {}", e);
+        }
+
         let sys = py.import("sys")?;
 
         // root and libraries path adding to sys.path
@@ -53,12 +111,16 @@ pub async fn handle_general_execute(code: String) -> PyResult<String,> {
         let trimmed = code.trim();
         let lines: Vec<&str> = trimmed.lines().collect();
 
-        let result: PyResult<Py<PyAny>> = if lines.len() <= 1 {
+        let watchdog = Watchdog::start(py, timeout)?;
+        // Hata da olsa stdout geri alınsın diye ? closure içinde kalır
+        let result: PyResult<Py<PyAny>> = (|| if lines.len() <= 1 {
             let code_cstr = std::ffi::CString::new(trimmed).map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string())
             })?;
+            // Deyimse (SyntaxError) exec ile; çalışıp hata verdiyse tekrar çalıştırılmaz
             match py.eval(&code_cstr, Some(&globals), None) {
                 Ok(val) => Ok(val.into()),
+                Err(e) if !e.is_instance_of::<PySyntaxError>(py) => Err(e),
                 Err(_) => {
                     py.run(&code_cstr, Some(&globals), None)?;
                     Ok(py.None())
@@ -78,12 +140,14 @@ pub async fn handle_general_execute(code: String) -> PyResult<String,> {
             })?;
             match py.eval(&last_cstr, Some(&globals), None) {
                 Ok(val) => Ok(val.into()),
+                Err(e) if !e.is_instance_of::<PySyntaxError>(py) => Err(e),
                 Err(_) => {
                     py.run(&last_cstr, Some(&globals), None)?;
                     Ok(py.None())
                 }
             }
-        };
+        })();
+        let timed_out = watchdog.stop(py);
 
         // stdout restore
         let captured_output: String = string_io.call_method0("getvalue")?.extract()?;
@@ -109,7 +173,11 @@ pub async fn handle_general_execute(code: String) -> PyResult<String,> {
             }
             Err(e) => {
                 sys.setattr("stdout", &original_stdout).ok();
-                let err_msg = format!("Python Error: {}", e);
+                let err_msg = if timed_out && e.is_instance_of::<PyTimeoutError>(py) {
+                    format!("Python Error: timed out after {}s", timeout.as_secs())
+                } else {
+                    format!("Python Error: {}", e)
+                };
                 if !captured_output.is_empty() {
                     Ok(format!("{}\n{}", captured_output.trim(), err_msg))
                 } else {
@@ -1,7 +1,13 @@
 use gemini_rust::prelude::*;
-use std::io::{self, Write};
 
+use crate::config;
 use crate::errors;
+use crate::memory_extract;
+use crate::memory_recall;
+use crate::oneshot;
+use crate::skill_context;
+use crate::tool_output;
+use crate::vault;
 use crate::action_executor::{process_ai_response, display_response, ExecuteResult};
 
 async fn generate_response_from_api(
@@ -27,38 +33,76 @@ async fn generate_response_from_api(
         .await
 }
 
-pub async fn gemini_api(api_key: String, prompt: String, model_type: String, tts_voice: String) -> Result<(), errors::OryxisError> {
-    let model = match model_type.as_str() {
+fn model_for(model_type: &str) -> Model {
+    match model_type {
         "Gemini3Pro"        => Model::Gemini3Pro,
         "Gemini3Flash"      => Model::Gemini3Flash,
         "Gemini25Pro"       => Model::Gemini25Pro,
         "Gemini25Flash"     => Model::Gemini25Flash,
         "Gemini25Flashlite" => Model::Gemini25FlashLite,
         _                   => Model::Gemini25Flash,
-    };
+    }
+}
+
+/// Sohbet context'inden bağımsız tek istek (hafıza çıkarımı)
+pub async fn complete(api_key: &str, model_type: &str, system: &str, user: &str) -> Result<String, errors::OryxisError> {
+    let client = Gemini::with_model(api_key, model_for(model_type))
+        .map_err(|e| errors::OryxisError::GeminiRunError(e.to_string()))?;
+    let temperature = config::generation().extract_temperature;
+    let config = GenerationConfig { temperature: Some(temperature), ..Default::default() };
+    let response = Gemini::generate_content(&client)
+        .with_system_prompt(system)
+        .with_generation_config(config)
+        .with_user_message(user)
+        .execute()
+        .await
+        .map_err(|e| errors::OryxisError::GeminiRunError(format!("{:?}", e)))?;
+    Ok(response.text().to_string())
+}
 
-    println!("MODEL: {}", model.as_str());
+pub async fn gemini_api(api_key: String, prompt: String, model_type: String, tts_voice: String) -> Result<(), errors::OryxisError> {
+    let model = model_for(&model_type);
+    let generation = config::generation();
+    let backend = memory_extract::Backend::Gemini { api_key: api_key.clone(), model: model_type.clone() };
+
+    oneshot::say(&format!("MODEL: {}", model.as_str()));
 
     let client = Gemini::with_model(&api_key, model)
         .map_err(|e| errors::OryxisError::GeminiRunError(e.to_string()))?;
     let mut ctx_builder = Gemini::generate_content(&client).with_system_prompt(&prompt);
 
-    println!("Oryxis hazır. Çıkmak için 'exit' yazın.\n");
+    if !oneshot::active() {
+        println!("Oryxis hazır. Çıkmak için 'exit' yazın.\n");
+    }
 
     loop {
-        print!("USER: ");
-        io::stdout().flush().unwrap();
-        let mut user_input = String::new();
-        io::stdin().read_line(&mut user_input).expect("Input error");
-        let user_input = user_input.trim().to_string();
-
-        if user_input == "exit" {
+        // Sohbette stdin, `oryxis run`'da görev (bir kez); None → çıkış
+        let Some(user_input) = oneshot::next_input() else {
+            memory_extract::finish(&backend).await;
             return Ok(());
+        };
+        if user_input == "/memories" {
+            if let Err(e) = memory_extract::review().await {
+                println!("{}", e);
+            }
+            continue;
         }
+        if user_input == "/why" {
+            memory_recall::why();
+            continue;
+        }
+        memory_extract::record_user(&user_input);
+        tool_output::new_turn();
+
+        // Gemini'de ara system mesajı yok; skill özeti kullanıcı mesajının önüne eklenir
+        let message = match skill_context::for_message(&user_input) {
+            Some(skills) => format!("{}\n\n{}", skills, user_input),
+            None => user_input,
+        };
 
         let response = generate_response_from_api(
-            Some(0.7), Some(0.9), None, None,
-            &user_input, &mut ctx_builder,
+            Some(generation.temperature), Some(generation.top_p), None, None,
+            &message, &mut ctx_builder,
         )
         .await
         .map_err(|e| errors::OryxisError::GeminiRunError(format!("{:?}", e)))?;
@@ -67,14 +111,16 @@ pub async fn gemini_api(api_key: String, prompt: String, model_type: String, tts
 
         loop {
             display_response(&current_text, &tts_voice).await;
+            memory_extract::record_assistant(&current_text);
 
             match process_ai_response(&current_text).await {
                 ExecuteResult::EndCode => break,
                 ExecuteResult::NoAction => break,
                 ExecuteResult::Output(result) => {
-                    let feedback = format!("Execution result:\n{}", result);
+                    memory_extract::record_result(&result);
+                    let feedback = tool_output::envelope(&result);
                     let next = generate_response_from_api(
-                        Some(0.7), Some(0.9), None, None,
+                        Some(generation.temperature), Some(generation.top_p), None, None,
                         &feedback, &mut ctx_builder,
                     )
                     .await
@@ -83,5 +129,7 @@ pub async fn gemini_api(api_key: String, prompt: String, model_type: String, tts
                 }
             }
         }
+        memory_extract::turn_finished(&backend);
+        vault::checkpoint();
     }
 }
@@ -1,11 +1,48 @@
 use groq_api_rs::completion::{client::Groq, message::Message, request::builder};
-use std::io::{self, Write};
 
 use crate::action_executor::{process_ai_response, display_response, ExecuteResult};
+use crate::config;
 use crate::errors;
+use crate::memory_extract;
+use crate::memory_recall;
+use crate::oneshot;
+use crate::skill_context;
+use crate::tool_output;
+use crate::vault;
 
+/// Sohbet geçmişinden bağımsız tek istek (hafıza çıkarımı)
+pub async fn complete(api_key: &str, model_type: &str, system: &str, user: &str) -> Result<String, errors::OryxisError> {
+    let mut client = Groq::new(api_key);
+    client.add_messages(vec![
+        Message::SystemMessage {
+            role: Some("system".to_string()),
+            content: Some(system.to_string()),
+            name: None,
+            tool_call_id: None,
+        },
+        Message::UserMessage {
+            role: Some("user".to_string()),
+            content: Some(user.to_string()),
+            name: None,
+            tool_call_id: None,
+        },
+    ]);
+
+    let request = builder::RequestBuilder::new(model_type.to_string()).with_temperature(config::generation().extract_temperature);
+    match client.create(request).await {
+        Ok(groq_api_rs::completion::client::CompletionOption::NonStream(response)) => Ok(response
+            .choices
+            .first()
+            .map(|choice| choice.message.content.clone())
+            .unwrap_or_default()),
+        Err(e) => Err(errors::OryxisError::GroqRunError(format!("{:?}", e))),
+        _ => Err(errors::OryxisError::GroqRunError("unexpected streaming response".to_string())),
+    }
+}
 
 pub async fn groq_api(api_key: String, prompt: String, model_type: String, tts_voice: String) -> Result<(), errors::OryxisError> {
+    let generation = config::generation();
+    let backend = memory_extract::Backend::Groq { api_key: api_key.clone(), model: model_type.clone() };
     let mut client = Groq::new(api_key.as_str());
     client.add_messages(vec![Message::SystemMessage {
         role: Some("system".to_string()),
@@ -14,17 +51,36 @@ pub async fn groq_api(api_key: String, prompt: String, model_type: String, tts_v
         tool_call_id: None,
     }]);
 
-    println!("Oryxis hazır. Çıkmak için 'exit' yazın.\n");
+    if !oneshot::active() {
+        println!("Oryxis hazır. Çıkmak için 'exit' yazın.\n");
+    }
 
     loop {
-        print!("USER: ");
-        io::stdout().flush().unwrap();
-        let mut user_input = String::new();
-        io::stdin().read_line(&mut user_input).expect("Input error");
-        let user_input = user_input.trim().to_string();
-
-        if user_input == "exit" {
+        // Sohbette stdin, `oryxis run`'da görev (bir kez); None → çıkış
+        let Some(user_input) = oneshot::next_input() else {
+            memory_extract::finish(&backend).await;
             return Ok(());
+        };
+        if user_input == "/memories" {
+            if let Err(e) = memory_extract::review().await {
+                println!("{}", e);
+            }
+            continue;
+        }
+        if user_input == "/why" {
+            memory_recall::why();
+            continue;
+        }
+        memory_extract::record_user(&user_input);
+        tool_output::new_turn();
+
+        if let Some(skills) = skill_context::for_message(&user_input) {
+            client.add_messages(vec![Message::SystemMessage {
+                role: Some("system".to_string()),
+                content: Some(skills),
+                name: None,
+                tool_call_id: None,
+            }]);
         }
 
         client.add_messages(vec![Message::UserMessage {
@@ -36,7 +92,9 @@ pub async fn groq_api(api_key: String, prompt: String, model_type: String, tts_v
 
         // Agentic inner loop
         loop {
-            let request = builder::RequestBuilder::new(model_type.clone());
+            let request = builder::RequestBuilder::new(model_type.clone())
+                .with_temperature(generation.temperature)
+                .with_top_p(generation.top_p);
             let res = client.create(request).await;
 
             match res {
@@ -45,6 +103,7 @@ pub async fn groq_api(api_key: String, prompt: String, model_type: String, tts_v
                     let content = choice.message.content.clone();
 
                     display_response(&content, &tts_voice).await;
+                    memory_extract::record_assistant(&content);
 
                     client.add_messages(vec![Message::AssistantMessage {
                         role: Some("assistant".to_string()),
@@ -58,9 +117,10 @@ pub async fn groq_api(api_key: String, prompt: String, model_type: String, tts_v
                         ExecuteResult::EndCode => break,
                         ExecuteResult::NoAction => break,
                         ExecuteResult::Output(result) => {
+                            memory_extract::record_result(&result);
                             client.add_messages(vec![Message::UserMessage {
                                 role: Some("user".to_string()),
-                                content: Some(format!("Execution result:\n{}", result)),
+                                content: Some(tool_output::envelope(&result)),
                                 name: None,
                                 tool_call_id: None,
                             }]);
@@ -71,6 +131,8 @@ pub async fn groq_api(api_key: String, prompt: String, model_type: String, tts_v
                 _ => break,
             }
         }
+        memory_extract::turn_finished(&backend);
+        vault::checkpoint();
     }
 
     #[allow(unreachable_code)]
@@ -0,0 +1,108 @@
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{js_string, Context, JsResult, JsValue, NativeFunction, Script, Source};
+use std::cell::RefCell;
+use std::future::Future;
+use std::task::{Poll, Waker};
+use std::time::{Duration, Instant};
+
+// Loop başına değil toplam iterasyon sınırı; sonsuz döngüye karşı ikinci emniyet
+const LOOP_ITERATION_LIMIT: u64 = 50_000_000;
+// Bu kadar "clock cycle"da bir boa durur, süre kontrol edilir
+const YIELD_BUDGET: u32 = 10_000;
+
+thread_local! {
+    static JS_STDOUT: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+fn js_print(_this: &JsValue, args: &[JsValue], ctx: &mut Context) -> JsResult<JsValue> {
+    let mut parts = Vec::with_capacity(args.len());
+    for arg in args {
+        parts.push(arg.to_string(ctx)?.to_std_string_escaped());
+    }
+    JS_STDOUT.with(|out| {
+        let mut out = out.borrow_mut();
+        out.push_str(&parts.join(" "));
+        out.push('\n');
+    });
+    Ok(JsValue::undefined())
+}
+
+/// Script'i boa'nın bütçeli yürütmesiyle çalıştırır; süre dolunca future bırakılır ve
+/// script orada durur. None → timeout.
+fn eval_with_deadline(ctx: &mut Context, code: &str, timeout: Duration) -> Option<JsResult<JsValue>> {
+    let script = match Script::parse(Source::from_bytes(code.as_bytes()), None, ctx) {
+        Ok(script) => script,
+        Err(e) => return Some(Err(e)),
+    };
+    let started = Instant::now();
+    let mut eval = std::pin::pin!(script.evaluate_async_with_budget(ctx, YIELD_BUDGET));
+    let mut poll_ctx = std::task::Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(result) = eval.as_mut().poll(&mut poll_ctx) {
+            return Some(result);
+        }
+        if started.elapsed() > timeout {
+            return None;
+        }
+    }
+}
+
+fn run_js(code: String, timeout: Duration) -> String {
+    JS_STDOUT.with(|out| out.borrow_mut().clear());
+
+    let mut ctx = Context::default();
+    ctx.runtime_limits_mut().set_loop_iteration_limit(LOOP_ITERATION_LIMIT);
+
+    // print() ve console.log/error stdout capture
+    let _ = ctx.register_global_builtin_callable(js_string!("print"), 1, NativeFunction::from_fn_ptr(js_print));
+    let console = ObjectInitializer::new(&mut ctx)
+        .function(NativeFunction::from_fn_ptr(js_print), js_string!("log"), 0)
+        .function(NativeFunction::from_fn_ptr(js_print), js_string!("info"), 0)
+        .function(NativeFunction::from_fn_ptr(js_print), js_string!("error"), 0)
+        .build();
+    let _ = ctx.register_global_property(js_string!("console"), console, Attribute::all());
+
+    let result = eval_with_deadline(&mut ctx, code.trim(), timeout);
+    let captured_output = JS_STDOUT.with(|out| out.borrow().trim().to_string());
+
+    let Some(result) = result else {
+        let err_msg = format!("JavaScript Error: timed out after {}s", timeout.as_secs());
+        return if captured_output.is_empty() { err_msg } else { format!("{}\n{}", captured_output, err_msg) };
+    };
+
+    match result {
+        Ok(val) if val.is_undefined() || val.is_null() => {
+            if captured_output.is_empty() { "None".to_string() } else { captured_output }
+        }
+        Ok(val) => {
+            let repr = match val.to_json(&mut ctx) {
+                Ok(json) => json.to_string(),
+                Err(_) => val.display().to_string(),
+            };
+            if captured_output.is_empty() {
+                repr
+            } else {
+                format!("{}\n[Return]: {}", captured_output, repr)
+            }
+        }
+        Err(e) => {
+            let err_msg = format!("JavaScript Error: {}", e);
+            if captured_output.is_empty() {
+                err_msg
+            } else {
+                format!("{}\n{}", captured_output, err_msg)
+            }
+        }
+    }
+}
+
+/// Gömülü boa motoruyla JavaScript çalıştırır.
+/// Son ifadenin değeri JSON olarak döner, print/console.log çıktısı yakalanır.
+/// Süre dolunca script durdurulur (thread arkada çalışmaya devam etmez).
+pub async fn handle_js_execute(code: String, timeout: Duration) -> String {
+    match tokio::task::spawn_blocking(move || run_js(code, timeout)).await {
+        Ok(result) => result,
+        Err(e) => format!("JavaScript Error: {}", e),
+    }
+}
@@ -1,50 +1,97 @@
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 use serde_json::json;
-use std::io::{self, Write};
 
 use crate::action_executor::{process_ai_response, display_response, ExecuteResult};
+use crate::config;
 use crate::errors;
+use crate::memory_extract;
+use crate::memory_recall;
+use crate::oneshot;
+use crate::skill_context;
+use crate::tool_output;
+use crate::vault;
+
+const API_URL: &str = "https://internal.llmapi.ai/v1/chat/completions";
+
+fn headers(api_key: &str) -> Result<HeaderMap, errors::OryxisError> {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.insert(
+        AUTHORIZATION,
+        HeaderValue::from_str(&format!("Bearer {}", api_key))
+            .map_err(|e| errors::OryxisError::LLMApiRunError(e.to_string()))?,
+    );
+    Ok(headers)
+}
+
+/// Sohbetten bağımsız tek istek (hafıza çıkarımı)
+pub async fn complete(api_key: &str, model: &str, system: &str, user: &str) -> Result<String, errors::OryxisError> {
+    let body = json!({
+        "model": model,
+        "messages": [
+            {"role": "system", "content": system},
+            {"role": "user", "content": user}
+        ],
+        "temperature": config::generation().extract_temperature
+    });
+
+    let res = reqwest::Client::new().post(API_URL).headers(headers(api_key)?).json(&body).send().await
+        .map_err(|e| errors::OryxisError::LLMApiRunError(e.to_string()))?;
+    if !res.status().is_success() {
+        return Err(errors::OryxisError::LLMApiRunError(res.status().to_string()));
+    }
+    let res_json: serde_json::Value = res.json().await
+        .map_err(|e| errors::OryxisError::LLMApiRunError(e.to_string()))?;
+    Ok(res_json["choices"][0]["message"]["content"].as_str().unwrap_or("").to_string())
+}
 
 pub async fn llmapi(api_key: String, prompt: String, model: String, tts_voice: String) -> Result<(), errors::OryxisError> {
-    let api_url = "https://internal.llmapi.ai/v1/chat/completions";
     let client = reqwest::Client::new();
+    let generation = config::generation();
+    let backend = memory_extract::Backend::LlmApi { api_key: api_key.clone(), model: model.clone() };
 
     let mut messages = vec![
         json!({"role": "system", "content": prompt})
     ];
 
-    println!("Oryxis hazır. Çıkmak için 'exit' yazın.\n");
+    if !oneshot::active() {
+        println!("Oryxis hazır. Çıkmak için 'exit' yazın.\n");
+    }
 
     loop {
-        print!("USER: ");
-        io::stdout().flush().unwrap();
-        let mut user_input = String::new();
-        io::stdin().read_line(&mut user_input).expect("Input error");
-        let user_input = user_input.trim().to_string();
-
-        if user_input == "exit" {
+        // Sohbette stdin, `oryxis run`'da görev (bir kez); None → çıkış
+        let Some(user_input) = oneshot::next_input() else {
+            memory_extract::finish(&backend).await;
             return Ok(());
+        };
+        if user_input == "/memories" {
+            if let Err(e) = memory_extract::review().await {
+                println!("{}", e);
+            }
+            continue;
+        }
+        if user_input == "/why" {
+            memory_recall::why();
+            continue;
         }
 
+        memory_extract::record_user(&user_input);
+        tool_output::new_turn();
+        if let Some(skills) = skill_context::for_message(&user_input) {
+            messages.push(json!({"role": "system", "content": skills}));
+        }
         messages.push(json!({"role": "user", "content": user_input}));
 
         // Agentic inner loop
         loop {
-            let mut headers = HeaderMap::new();
-            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", api_key))
-                    .map_err(|e| errors::OryxisError::LLMApiRunError(e.to_string()))?,
-            );
-
             let body = json!({
                 "model": model,
                 "messages": messages,
-                "temperature": 0.7
+                "temperature": generation.temperature,
+                "top_p": generation.top_p
             });
 
-            let res = client.post(api_url).headers(headers).json(&body).send().await
+            let res = client.post(API_URL).headers(headers(&api_key)?).json(&body).send().await
                 .map_err(|e| errors::OryxisError::LLMApiRunError(e.to_string()))?;
 
             if !res.status().is_success() {
@@ -61,17 +108,22 @@ pub async fn llmapi(api_key: String, prompt: String, model: String, tts_voice: S
 
             display_response(&ai_answer, &tts_voice).await;
             messages.push(json!({"role": "assistant", "content": ai_answer}));
+            memory_extract::record_assistant(&ai_answer);
 
             match process_ai_response(&ai_answer).await {
                 ExecuteResult::EndCode => break,
                 ExecuteResult::NoAction => break,
                 ExecuteResult::Output(result) => {
+                    memory_extract::record_result(&result);
+                    // system değil: dosya/sayfa içeriği talimat yetkisi almasın
                     messages.push(json!({
-                        "role": "system",
-                        "content": format!("Execution result:\n{}", result)
+                        "role": "user",
+                        "content": tool_output::envelope(&result)
                     }));
                 }
             }
         }
+        memory_extract::turn_finished(&backend);
+        vault::checkpoint();
     }
 }
\ No newline at end of file
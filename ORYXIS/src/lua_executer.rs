@@ -0,0 +1,84 @@
+use mlua::{HookTriggers, Lua, LuaOptions, LuaSerdeExt, MultiValue, StdLib, Variadic};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn run_lua(code: String, timeout: Duration) -> String {
+    // io/os/package yok: JS tarafı gibi sadece hesaplama
+    let libs = StdLib::TABLE | StdLib::STRING | StdLib::MATH | StdLib::UTF8 | StdLib::COROUTINE;
+    let lua = match Lua::new_with(libs, LuaOptions::default()) {
+        Ok(l) => l,
+        Err(e) => return format!("Lua Error: {}", e),
+    };
+    let captured = Arc::new(Mutex::new(String::new()));
+
+    // print() stdout capture
+    let out = captured.clone();
+    let print = lua.create_function(move |_, args: Variadic<mlua::Value>| {
+        let parts: Vec<String> = args
+            .iter()
+            .map(|v| v.to_string().unwrap_or_else(|_| format!("{:?}", v)))
+            .collect();
+        let mut out = out.lock().unwrap();
+        out.push_str(&parts.join("\t"));
+        out.push('\n');
+        Ok(())
+    });
+    match print {
+        Ok(f) => {
+            if let Err(e) = lua.globals().set("print", f) {
+                return format!("Lua Error: {}", e);
+            }
+        }
+        Err(e) => return format!("Lua Error: {}", e),
+    }
+
+    // Timeout: her 10k instruction'da süre kontrolü
+    let started = Instant::now();
+    lua.set_hook(HookTriggers::new().every_nth_instruction(10_000), move |_, _| {
+        if started.elapsed() > timeout {
+            Err(mlua::Error::RuntimeError(format!("timed out after {}s", timeout.as_secs())))
+        } else {
+            Ok(())
+        }
+    });
+
+    let result = lua.load(code.trim()).set_name("script").eval::<MultiValue>();
+    let captured_output = captured.lock().unwrap().trim().to_string();
+
+    match result {
+        Ok(values) => {
+            let reprs: Vec<String> = values
+                .iter()
+                .filter(|v| !v.is_nil())
+                .map(|v| match lua.from_value::<serde_json::Value>(v.clone()) {
+                    Ok(json) => json.to_string(),
+                    Err(_) => v.to_string().unwrap_or_else(|_| format!("{:?}", v)),
+                })
+                .collect();
+
+            if reprs.is_empty() {
+                if captured_output.is_empty() { "None".to_string() } else { captured_output }
+            } else if captured_output.is_empty() {
+                reprs.join(", ")
+            } else {
+                format!("{}\n[Return]: {}", captured_output, reprs.join(", "))
+            }
+        }
+        Err(e) => {
+            let err_msg = format!("Lua Error: {}", e);
+            if captured_output.is_empty() {
+                err_msg
+            } else {
+                format!("{}\n{}", captured_output, err_msg)
+            }
+        }
+    }
+}
+
+/// Gömülü Lua 5.4 ile kod çalıştırır. `return` edilen değerler JSON olarak döner.
+pub async fn handle_lua_execute(code: String, timeout: Duration) -> String {
+    match tokio::task::spawn_blocking(move || run_lua(code, timeout)).await {
+        Ok(result) => result,
+        Err(e) => format!("Lua Error: {}", e),
+    }
+}
@@ -6,6 +6,33 @@ pub mod script;                 // RESPONSE CATCHER
 pub mod errors;                 // ERROR TYPES
 pub mod action_executor;        // ACTION EXECUTOR
 pub mod tts;                    // TTS MODULE
+pub mod artifacts;              // LARGE OUTPUT ARTIFACTS
+pub mod shell_executer;         // SHELL CODE EXECUTER
+pub mod js_executer;            // JAVASCRIPT CODE EXECUTER
+pub mod lua_executer;           // LUA CODE EXECUTER
+pub mod venv;                   // SKILL PYTHON PACKAGES
+pub mod skill_manifest;         // SKILL YAML MODEL
+pub mod skill_index;            // SKILLS_INDEX.JSON GENERATOR
+pub mod skill_context;          // PER-TURN SKILL INJECTION
+pub mod commands;               // CLI SUBCOMMANDS
+pub mod plugins;                // NATIVE SKILL LOADER
+pub mod py_bridge;              // `import oryxis` PYTHON MODULE
+pub mod wasm_skills;            // SANDBOXED WASM SKILLS
+pub mod permissions;            // SKILL PERMISSIONS / GRANTS
+pub mod skill_watch;            // SKILL HOT RELOAD
+pub mod skill_scaffold;         // `oryxis skill new/build`
+pub mod skill_test;             // `oryxis skills test`
+pub mod skill_bundle;           // .oryxskill PACK / INSTALL
+pub mod memory_extract;         // LONG-TERM MEMORY EXTRACTION
+pub mod memory_recall;          // PER-TURN MEMORY INJECTION
+pub mod rocksdb_reader;         // READ-ONLY ROCKSDB READER
+pub mod prototype_import;       // `oryxis import` (PROTOTYPE MYDB)
+pub mod vault;                  // ENCRYPTED STORAGE
+pub mod redact;                 // SECRET REDACTION
+pub mod tool_output;            // TOOL OUTPUT ENVELOPES / INJECTION GUARD
+pub mod config;                 // LAYERED ORYXIS.TOML
+pub mod cli;                    // CLAP COMMAND LINE
+pub mod oneshot;                // `oryxis run` / `oryxis -`
 
 
 use dotenv::dotenv;             // READING .ENV FILE
@@ -13,43 +40,100 @@ use std::env;                   // READING .ENV FILE
 use tokio;                      // ASYNC PROCESS
 use std::fs::File;              // READING PROMPT.TXT
 use std::io::{self, Read};      // READING PROMPT.TXT
+use std::process::ExitCode;     // `oryxis run` EXIT CODE
+use clap::Parser;               // COMMAND LINE
 
 #[tokio::main]
-async fn main() -> Result<(), errors::OryxisError> {
+async fn main() -> Result<ExitCode, errors::OryxisError> {
     dotenv().ok();
+    // Skill kütüphaneleri (memorylib) kaydı yazan session'ı buradan okur
+    // SAFETY: bu noktada env'e dokunan başka iş yok (dotenv de aynısını yapıyor)
+    unsafe { env::set_var("ORYXIS_SESSION", artifacts::session_id()) };
 
-    // Env Settings
-    let api_key = env::var("API_KEY");
-    let api_type = env::var("API_TYPE");
-    let llm_model = env::var("LLM_MODEL");
-    let tts_voice = env::var("TTS").unwrap_or_default();
+    let cli = cli::Cli::parse();
+    // --set, --model, --provider, --prompt, --no-exec → en üst ayar katmanı
+    let overrides = cli.global.overrides();
 
-    let api_key = match api_key {
-        Ok(val) => val,
-        Err(e) => {
-            println!("Error API_KEY: {}", e);
-            return Err(errors::OryxisError::WrongApiKey(e.to_string()));
+    let task = match cli.command {
+        Some(cli::Command::Run { task }) => Some(task),
+        Some(cli::Command::Stdin) => Some(vec!["-".to_string()]),
+        None | Some(cli::Command::Chat) => None,
+        // Yönetim komutları chat başlatmadan çalışır (oryxis deps install, ...)
+        Some(command) => {
+            config::load(&overrides)?;
+            commands::run(command)?;
+            return Ok(ExitCode::SUCCESS);
         }
     };
 
-    let api_type = match api_type {
-        Ok(val) => val,
-        Err(e) => {
-            println!("Error API_TYPE: {}", e);
-            return Err(errors::OryxisError::ApiTypeError(e.to_string()));
-        }
+    let Some(task) = task else {
+        start(&overrides).await?;
+        return Ok(ExitCode::SUCCESS);
     };
-
-    let llm_model = match llm_model {
-        Ok(val) => val,
-        Err(e) => {
-            println!("Error LLM_MODEL: {}", e);
-            return Err(errors::OryxisError::LlmModelError(e.to_string()));
-        }
+    // Tek görev: hata da çıkış koduna (--json'da JSON'a) dönüşür
+    let outcome = match oneshot::start(&task, cli.global.json) {
+        Ok(()) => start(&overrides).await,
+        Err(e) => Err(e),
     };
+    Ok(oneshot::finish(outcome))
+}
 
+/// Sohbet ya da tek görev: vault, ayarlar, modüller, sonra seçili backend
+async fn start(overrides: &[(String, String)]) -> Result<(), errors::OryxisError> {
+    // memory/vault.json varsa: hafıza şifreli, anahtarlar vault'ta (düşünce her şey yeniden şifrelenir)
+    let _vault = vault::open()?;
+
+    // Ayarlar: varsayılan → sistem → kullanıcı → proje oryxis.toml → env (.env, vault) → komut satırı
+    let config = config::load(overrides)?;
+    let api_key = config.required("provider.api_key").map_err(|e| {
+        oneshot::say(&format!("Error API_KEY: {}", e));
+        errors::OryxisError::WrongApiKey(e.to_string())
+    })?;
+    let api_type = config.required("provider.type").map_err(|e| {
+        oneshot::say(&format!("Error API_TYPE: {}", e));
+        errors::OryxisError::ApiTypeError(e.to_string())
+    })?;
+    let llm_model = config.required("provider.model").map_err(|e| {
+        oneshot::say(&format!("Error LLM_MODEL: {}", e));
+        errors::OryxisError::LlmModelError(e.to_string())
+    })?;
+    let tts_voice = config.str("tts.voice").unwrap_or_default();
+    let output_limit = config.usize("executor.output_limit");
+    let exec_timeout = config.usize("executor.timeout") as u64;
+    // executor.enabled = false (--no-exec) → action'lar gösterilir ama çalıştırılmaz
+    let exec_enabled = config.bool("executor.enabled");
+    let skill_context_tokens = config.usize("skills.context_tokens");
+    // skills.watch = false → skills/ değişiklikleri için yeniden başlatmak gerekir
+    let skill_watch = config.bool("skills.watch");
+    let memory_context_tokens = config.usize("memory.context_tokens");
+    // memory.extract = false → konuşmadan otomatik hafıza çıkarımı yok; extract_turns = 0 → sadece çıkışta
+    let memory_extract = config.bool("memory.extract");
+    let memory_extract_turns = config.usize("memory.extract_turns");
+    // security.redact = false → execution çıktısındaki anahtar/parola/token'lar maskelenmez
+    let redact_enabled = config.bool("security.redact");
+    // security.injection_guard = false → talimat benzeri çıktıdan sonraki action'lar onay istemez
+    let injection_guard = config.bool("security.injection_guard");
+    let prompt_path = config.str("generation.system_prompt").unwrap_or_default();
+
+    // Bilinen anahtarların kendisi de çıktıda aranır
+    let mut known_secrets: Vec<(String, String)> = vault::secrets().into_iter().collect();
+    known_secrets.extend(env::vars().filter(|(name, _)| redact::is_secret_name(name)));
+    known_secrets.push(("API_KEY".to_string(), api_key.clone()));
+    let redact_rules = redact::Rules { patterns: config.map("security.redact_patterns"), allow: config.list("security.redact_allow") };
+    redact::init(redact_enabled, known_secrets, redact_rules);
+    tool_output::init(injection_guard);
+    artifacts::init(output_limit)?;
+    action_executor::init(exec_timeout, exec_enabled);
+    skill_index::reindex_on_startup();
+    plugins::load_on_startup();
+    skill_context::init(skill_context_tokens);
+    if skill_watch {
+        skill_watch::start();
+    }
+    memory_extract::init(memory_extract, memory_extract_turns);
+    memory_recall::init(memory_context_tokens);
 
-    let mut file = File::open("prompt.md").map_err(|e| errors::OryxisError::PromptFileError(e.to_string()))?;
+    let mut file = File::open(&prompt_path).map_err(|e| errors::OryxisError::PromptFileError(format!("{}: {}", prompt_path, e)))?;
     let mut contents = String::new();
     file.read_to_string(&mut contents).map_err(|e| errors::OryxisError::PromptFileError(e.to_string()))?;
 
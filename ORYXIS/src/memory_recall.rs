@@ -0,0 +1,271 @@
+// Her kullanıcı mesajından önce ilgili hafıza kayıtlarını bulur ve kısa bir
+// "Known facts" bloğu olarak context'e ekler; model memorylib.recall için execution harcamaz.
+//
+// Arama hibrit: BM25 (skill_lib::search, İngilizce + Türkçe kök) + embedding kosinüsü
+// (skill_lib::embed, `semantic` özelliği ve memory/models/embedding/ varsa). Kayıt vektörleri
+// memory/memory_vectors.json'da key + değer hash'iyle saklanır, değişenler yeniden hesaplanır.
+// Bu session'da aynı değerle zaten eklenmiş kayıt tekrar eklenmez. `/why` son mesajda neyin
+// neden eklendiğini gösterir.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+use crate::plugins;
+use crate::vault;
+
+pub const DEFAULT_MEMORY_CONTEXT_TOKENS: usize = 300;
+const SKILL: &str = "memorylib";
+const TOP_K: usize = 5;
+// Hibrit skorun altı gürültü
+const MIN_SCORE: f64 = 0.3;
+const VECTORS_PATH: &str = "memory/memory_vectors.json";
+
+struct Recall {
+    token_budget: usize,
+    /// key → bu session'da eklenen değer
+    injected: HashMap<String, String>,
+    last: Vec<Match>,
+    last_message: String,
+}
+
+#[derive(Clone)]
+struct Match {
+    key: String,
+    value: String,
+    keyword: f64,
+    semantic: Option<f64>,
+    score: f64,
+    status: Status,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Status {
+    Injected,
+    AlreadyInContext,
+    OverBudget,
+}
+
+static RECALL: OnceLock<Mutex<Recall>> = OnceLock::new();
+
+/// `token_budget` = 0 → injection kapalı.
+pub fn init(token_budget: usize) {
+    let _ = RECALL.set(Mutex::new(Recall { token_budget, injected: HashMap::new(), last: Vec::new(), last_message: String::new() }));
+}
+
+// Kaba tahmin: ~4 karakter = 1 token
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+fn str_field<'a>(entry: &'a Value, field: &str) -> &'a str {
+    entry[field].as_str().unwrap_or_default()
+}
+
+fn document(entry: &Value) -> String {
+    format!("{}: {} ({})", str_field(entry, "key").replace('_', " "), str_field(entry, "value"), str_field(entry, "category"))
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct VectorCache {
+    /// key → (doküman hash'i, vektör)
+    entries: BTreeMap<String, (u64, Vec<f32>)>,
+}
+
+// FNV-1a: skill_lib'in vektör cache'iyle aynı, Rust sürümünden bağımsız
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |h, b| (h ^ *b as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Sorgu ve kayıtların kosinüs benzerlikleri; embedding yoksa None.
+fn semantic_scores(message: &str, memories: &[Value]) -> Option<Vec<f64>> {
+    let query = skill_lib::embed(message).ok()?;
+    let path = vault::path(VECTORS_PATH);
+    let mut cache: VectorCache = std::fs::read_to_string(&path).ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+
+    let mut changed = false;
+    let mut scores = Vec::with_capacity(memories.len());
+    for entry in memories {
+        let key = str_field(entry, "key").to_string();
+        let doc = document(entry);
+        let hash = fnv1a(doc.as_bytes());
+        let vector = match cache.entries.get(&key) {
+            Some((h, v)) if *h == hash => v.clone(),
+            _ => {
+                let v = skill_lib::embed(&doc).ok()?;
+                cache.entries.insert(key, (hash, v.clone()));
+                changed = true;
+                v
+            }
+        };
+        scores.push(vector.iter().zip(&query).map(|(a, b)| a * b).sum::<f32>().max(0.0) as f64);
+    }
+
+    // Silinen kayıtların vektörleri de gitsin
+    let live: std::collections::HashSet<&str> = memories.iter().map(|e| str_field(e, "key")).collect();
+    let before = cache.entries.len();
+    cache.entries.retain(|k, _| live.contains(k.as_str()));
+    changed |= cache.entries.len() != before;
+
+    if changed
+        && let Ok(json) = serde_json::to_string(&cache)
+    {
+        let _ = std::fs::write(&path, json);
+    }
+    Some(scores)
+}
+
+/// En ilgili kayıtlar, iyi olan önde
+fn rank(message: &str, memories: &[Value]) -> Vec<Match> {
+    let docs: Vec<String> = memories.iter().map(document).collect();
+    let mut keyword = vec![0.0; memories.len()];
+    for (i, score) in skill_lib::search::rank_texts(&docs, message, memories.len()) {
+        keyword[i] = score;
+    }
+    // BM25 sınırsız ve kayıt sayısıyla büyür; sorgunun bu kayıtlarda alabileceği en yüksek
+    // skora bölünür, tek kayıtlı hafızada da eşik aynı anlamda kalır
+    let ceiling = skill_lib::search::ceiling_texts(&docs, message);
+    let semantic = semantic_scores(message, memories);
+
+    let mut matches: Vec<Match> = memories
+        .iter()
+        .enumerate()
+        .filter_map(|(i, entry)| {
+            let kw = if ceiling > 0.0 { keyword[i] / ceiling } else { 0.0 };
+            let sem = semantic.as_ref().map(|s| s[i]);
+            let relevance = match sem {
+                Some(sem) => 0.5 * kw + 0.5 * sem,
+                None => kw,
+            };
+            if relevance < MIN_SCORE {
+                return None;
+            }
+            let importance = entry["importance"].as_f64().unwrap_or(0.5);
+            Some(Match {
+                key: str_field(entry, "key").to_string(),
+                value: str_field(entry, "value").to_string(),
+                keyword: kw,
+                semantic: sem,
+                score: relevance * (0.75 + 0.25 * importance),
+                status: Status::Injected,
+            })
+        })
+        .collect();
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches.truncate(TOP_K);
+    matches
+}
+
+/// Mesajla ilgili hafıza bloğu; bütçe dolunca kesilir. Yeni bir şey yoksa None.
+pub fn for_message(message: &str) -> Option<String> {
+    let recall = RECALL.get()?;
+    if recall.lock().unwrap().token_budget == 0 || plugins::describe(SKILL).is_none() {
+        return None;
+    }
+    let memories = match plugins::invoke(SKILL, "list_all", &[Value::Null]) {
+        Ok(Value::Array(all)) if !all.is_empty() => all,
+        Ok(_) => return None,
+        Err(e) => {
+            eprintln!("[MEMORY] {}", e);
+            return None;
+        }
+    };
+    let mut matches = rank(message, &memories);
+
+    let mut recall = recall.lock().unwrap();
+    let header = "[Known facts — from long-term memory, no need to call memorylib.recall]";
+    let mut block = header.to_string();
+    let mut used = estimate_tokens(header);
+    let mut added = Vec::new();
+
+    for m in matches.iter_mut() {
+        if recall.injected.get(&m.key) == Some(&m.value) {
+            m.status = Status::AlreadyInContext;
+            continue;
+        }
+        let line = format!("- {}: {}", m.key, m.value);
+        let cost = estimate_tokens(&line) + 1;
+        if used + cost > recall.token_budget {
+            m.status = Status::OverBudget;
+            continue;
+        }
+        block.push('\n');
+        block.push_str(&line);
+        used += cost;
+        added.push((m.key.clone(), m.value.clone()));
+    }
+
+    recall.last = matches;
+    recall.last_message = message.to_string();
+    if added.is_empty() {
+        return None;
+    }
+    eprintln!("[MEMORY] injected {} fact(s) (~{} tokens)", added.len(), used);
+    recall.injected.extend(added);
+    Some(block)
+}
+
+/// `/why` — son mesaj için bulunan hafıza kayıtları ve skorları
+pub fn why() {
+    let Some(recall) = RECALL.get() else { return };
+    let recall = recall.lock().unwrap();
+    if recall.token_budget == 0 {
+        println!("Memory injection is off (MEMORY_CONTEXT_TOKENS=0).");
+        return;
+    }
+    if recall.last_message.is_empty() {
+        println!("No message yet.");
+        return;
+    }
+    if recall.last.is_empty() {
+        println!("No memories matched \"{}\".", recall.last_message);
+        return;
+    }
+
+    println!("Memories for \"{}\":", recall.last_message);
+    for m in &recall.last {
+        let status = match m.status {
+            Status::Injected => "injected",
+            Status::AlreadyInContext => "already in context",
+            Status::OverBudget => "skipped, over token budget",
+        };
+        let semantic = m.semantic.map(|s| format!(", semantic {:.2}", s)).unwrap_or_default();
+        println!("  {} = {}", m.key, m.value);
+        println!("      score {:.2} (keyword {:.2}{}) — {}", m.score, m.keyword, semantic, status);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn single_memory_store_still_matches() {
+        let memories = vec![json!({"key": "user_name", "value": "Kuzey", "category": "user", "importance": 0.8})];
+        let matches = rank("what is my name", &memories);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].key, "user_name");
+        assert!(matches[0].keyword <= 1.0);
+    }
+
+    #[test]
+    fn unrelated_message_matches_nothing() {
+        let memories = vec![json!({"key": "user_name", "value": "Kuzey", "category": "user", "importance": 0.8})];
+        assert!(rank("play some music", &memories).is_empty());
+    }
+
+    #[test]
+    fn score_does_not_depend_on_store_size() {
+        let target = json!({"key": "favorite_editor", "value": "Neovim", "category": "preference"});
+        let filler: Vec<Value> = (0..20)
+            .map(|i| json!({"key": format!("fact_{}", i), "value": format!("unrelated detail number {}", i), "category": "general"}))
+            .collect();
+        let small = rank("which editor do I like", std::slice::from_ref(&target));
+        let large = rank("which editor do I like", &[filler, vec![target]].concat());
+        assert_eq!(small.len(), 1);
+        assert_eq!(large.len(), 1);
+        assert!(large[0].keyword >= small[0].keyword);
+    }
+}
@@ -0,0 +1,167 @@
+// `oryxis run "<görev>"` / `oryxis -`: tek görev, soru sormadan çalışır ve çıkar (script, cron).
+//
+// Backend'ler sohbetteki döngüyle çalışır; kullanıcı mesajını stdin yerine next_input verir.
+// Cevaplar ve action'lar kaydedilir; --json'da sonunda stdout'a tek satır JSON yazılır:
+//
+//   {"ok":true,"exit_code":0,"task":"...","answer":"...","error":null,
+//    "actions":[{"language":"shell","code":"...","ran":true,"ok":true,"result":"..."}]}
+//
+// Çıkış kodu: 0 bitti, 1 Oryxis / sağlayıcı hatası, 2 son action hata verdi ya da kullanıcı reddetti
+// (--no-exec ile atlananlar hata sayılmaz).
+
+use serde::Serialize;
+use serde_json::json;
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::ExitCode;
+use std::sync::Mutex;
+
+use crate::errors::OryxisError;
+
+const EXIT_ERROR: u8 = 1;
+const EXIT_FAILED_ACTION: u8 = 2;
+
+#[derive(Serialize)]
+struct Action {
+    language: String,
+    code: String,
+    ran: bool,
+    ok: bool,
+    result: String,
+}
+
+struct Run {
+    task: String,
+    json: bool,
+    /// Görev backend'e verildi mi (ikinci next_input → çıkış)
+    given: bool,
+    answer: String,
+    actions: Vec<Action>,
+}
+
+/// None → sohbet
+static RUN: Mutex<Option<Run>> = Mutex::new(None);
+
+fn run_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::RunError(msg.to_string())
+}
+
+/// Tek görev modunu açar. `task` = ["-"] ise görev stdin'in tamamıdır.
+pub fn start(task: &[String], json: bool) -> Result<(), OryxisError> {
+    // Görev okunamazsa da hata JSON olarak yazılsın diye önce mod
+    *RUN.lock().unwrap() = Some(Run { task: String::new(), json, given: false, answer: String::new(), actions: Vec::new() });
+
+    let task = if task == ["-"] {
+        if io::stdin().is_terminal() {
+            eprintln!("Reading the task from stdin, end it with Ctrl-D.");
+        }
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).map_err(|e| run_err(format!("stdin: {}", e)))?;
+        text
+    } else {
+        task.join(" ")
+    };
+    let task = task.trim();
+    if task.is_empty() {
+        return Err(run_err("the task is empty"));
+    }
+    if let Some(run) = RUN.lock().unwrap().as_mut() {
+        run.task = task.to_string();
+    }
+    Ok(())
+}
+
+pub fn active() -> bool {
+    RUN.lock().unwrap().is_some()
+}
+
+fn json() -> bool {
+    RUN.lock().unwrap().as_ref().is_some_and(|run| run.json)
+}
+
+/// Kullanıcıya yönelik çıktı; --json'da stdout sadece sonuca kalsın diye stderr'e
+pub fn say(text: &str) {
+    if json() {
+        eprintln!("{}", text);
+    } else {
+        println!("{}", text);
+    }
+}
+
+/// Cevap beklenen soru (satır sonu yok)
+pub fn say_prompt(text: &str) {
+    if json() {
+        eprint!("{}", text);
+        io::stderr().flush().unwrap();
+    } else {
+        print!("{}", text);
+        io::stdout().flush().unwrap();
+    }
+}
+
+/// Sıradaki kullanıcı mesajı; None → oturum biter. Sohbette stdin'den ("exit" ya da EOF),
+/// tek görevde görevin kendisi, bir kez.
+pub fn next_input() -> Option<String> {
+    if let Some(run) = RUN.lock().unwrap().as_mut() {
+        if run.given {
+            return None;
+        }
+        run.given = true;
+        return Some(run.task.clone());
+    }
+
+    print!("USER: ");
+    io::stdout().flush().unwrap();
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => {
+            println!();
+            None
+        }
+        Ok(_) => Some(line.trim().to_string()).filter(|input| input != "exit"),
+    }
+}
+
+/// Modelin (maskelenmiş) cevabı; sonuncusu `answer` olur
+pub fn record_answer(text: &str) {
+    if let Some(run) = RUN.lock().unwrap().as_mut() {
+        run.answer = text.replace("<ENDCODE>", "").replace("<EXECUTION_COMPLETE>", "").trim().to_string();
+    }
+}
+
+/// İstenen action; `ran` false → --no-exec (ok) ya da kullanıcı reddetti (ok değil)
+pub fn record_action(language: &str, code: &str, ran: bool, ok: bool, result: &str) {
+    if let Some(run) = RUN.lock().unwrap().as_mut() {
+        run.actions.push(Action {
+            language: language.to_string(),
+            code: code.to_string(),
+            ran,
+            ok,
+            result: result.to_string(),
+        });
+    }
+}
+
+/// Oturum kapandıktan sonra (vault mühürlendi): --json'da sonucu yazar, çıkış kodunu verir
+pub fn finish(outcome: Result<(), OryxisError>) -> ExitCode {
+    let Some(run) = RUN.lock().unwrap().take() else { return ExitCode::SUCCESS };
+    let code = match &outcome {
+        Err(_) => EXIT_ERROR,
+        Ok(()) if run.actions.last().is_some_and(|action| !action.ok) => EXIT_FAILED_ACTION,
+        Ok(()) => 0,
+    };
+
+    if run.json {
+        let report = json!({
+            "ok": code == 0,
+            "exit_code": code,
+            "task": run.task,
+            "answer": run.answer,
+            "actions": run.actions,
+            "error": outcome.as_ref().err().map(|e| e.to_string()),
+        });
+        println!("{}", report);
+    } else if let Err(e) = &outcome {
+        eprintln!("Error: {}", e);
+    }
+    ExitCode::from(code)
+}
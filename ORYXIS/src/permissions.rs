@@ -0,0 +1,235 @@
+// Skill izinleri: manifest'te `permissions` altında beyan edilir, kullanıcı
+// oryxis.toml'da `[permissions.<skill>]` (grant = [..], revoke = [..]) ile skill bazında
+// ekleyip kaldırabilir. Bunlar config katmanlarından gelir (bkz. config, `oryxis config show`).
+//
+//   fs.read:<paths>            fs.write:<paths>      (virgülle ayrılmış, proje köküne göre, ~ = home, * = her yer)
+//   process.spawn:<programs>   (* = hepsi)
+//
+// Ağ erişimi izin değil: native ve Python skill'lerde onu kısıtlayacak bir katman yok,
+// WASM skill'lerde ise hiç ağ yok. O yüzden `net:` beyanı kabul edilmez.
+//
+// Vault anahtarı, .env, vault.json ve oryxis.toml dosyaları hiçbir izinle açılmaz (bkz. protected).
+//
+// Etkin izinler = beyan edilenler + grant − revoke. `fs.write` gibi listesiz bir
+// revoke o türün tamamını kaldırır. Native skill'ler bunları oryxis_skill_configure
+// ile alıp kendileri uygular, WASM skill'lerde sandbox'ın kendisi bunlardan kurulur.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::config;
+use crate::errors::OryxisError;
+use crate::skill_manifest::{self, SkillManifest};
+use crate::vault;
+
+const KINDS: &[&str] = &["fs.read", "fs.write", "process.spawn"];
+
+/// Tek bir izin: tür + tek öğe ("fs.read", "/home/u/docs")
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Permission {
+    pub kind: String,
+    pub item: String,
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.kind, self.item)
+    }
+}
+
+impl Permission {
+    /// fs izinlerinin host'taki mutlak yolu ("*" → kök)
+    pub fn host_path(&self) -> PathBuf {
+        if self.item == "*" {
+            return PathBuf::from(if cfg!(windows) { "C:\\" } else { "/" });
+        }
+        match self.item.strip_prefix('~') {
+            Some(rest) => std::env::var_os(if cfg!(windows) { "USERPROFILE" } else { "HOME" })
+                .map(PathBuf::from)
+                .unwrap_or_default()
+                .join(rest.trim_start_matches(['/', '\\'])),
+            None => std::env::current_dir().unwrap_or_default().join(&self.item),
+        }
+    }
+}
+
+/// "fs.read:a,b" → [fs.read:a, fs.read:b]
+pub fn parse(permission: &str) -> Result<Vec<Permission>, OryxisError> {
+    let (kind, list) = permission
+        .split_once(':')
+        .ok_or_else(|| OryxisError::InvalidSkill(format!("permission `{}` has no `:<value>`", permission)))?;
+    let kind = kind.trim();
+    if kind == "net" {
+        return Err(OryxisError::InvalidSkill(format!(
+            "`{}`: network access can't be granted, nothing enforces it (native and Python skills can always connect, WASM skills never can)",
+            permission
+        )));
+    }
+    if !KINDS.contains(&kind) {
+        return Err(OryxisError::InvalidSkill(format!(
+            "unknown permission `{}` (expected one of {})", kind, KINDS.join(", ")
+        )));
+    }
+    let items: Vec<Permission> = list
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|item| Permission { kind: kind.to_string(), item: item.to_string() })
+        .collect();
+    if items.is_empty() {
+        return Err(OryxisError::InvalidSkill(format!("permission `{}` lists nothing", permission)));
+    }
+    Ok(items)
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SkillGrants {
+    #[serde(default)]
+    pub grant: Vec<String>,
+    #[serde(default)]
+    pub revoke: Vec<String>,
+}
+
+/// Birleşmiş config'teki `[permissions.<skill>]` tabloları
+pub fn load_grants() -> BTreeMap<String, SkillGrants> {
+    config::get()
+        .section("permissions")
+        .into_iter()
+        .filter_map(|(skill, value)| Some((skill.to_string(), value.clone().try_into().ok()?)))
+        .collect()
+}
+
+/// Skill'in birleşmiş grant/revoke'unu proje oryxis.toml'una yazar (alt katmanları o skill için ezer)
+fn save_grants(skill: &str, grants: &SkillGrants) -> Result<PathBuf, OryxisError> {
+    config::edit_project(|doc| {
+        let section = doc.entry("permissions").or_insert_with(|| {
+            let mut table = toml_edit::Table::new();
+            table.set_implicit(true);
+            toml_edit::Item::Table(table)
+        });
+        let entry = &mut section[skill];
+        if !entry.is_table() {
+            *entry = toml_edit::table();
+        }
+        entry["grant"] = toml_edit::value(grants.grant.iter().collect::<toml_edit::Array>());
+        entry["revoke"] = toml_edit::value(grants.revoke.iter().collect::<toml_edit::Array>());
+    })
+}
+
+/// Beyan + grant − revoke. Geçersiz string'ler atlanır (validate bunları raporlar).
+pub fn effective(manifest: &SkillManifest) -> Vec<Permission> {
+    let user = load_grants().remove(&manifest.name).unwrap_or_default();
+
+    let mut permissions: Vec<Permission> = manifest
+        .permissions
+        .iter()
+        .chain(user.grant.iter())
+        .filter_map(|p| parse(p).ok())
+        .flatten()
+        .collect();
+    permissions.sort();
+    permissions.dedup();
+
+    for revoke in &user.revoke {
+        match parse(revoke) {
+            Ok(items) => permissions.retain(|p| !items.contains(p)),
+            // "fs.write" → o türün hepsi
+            Err(_) => permissions.retain(|p| p.kind != revoke.trim()),
+        }
+    }
+    permissions
+}
+
+/// Dosya yollarını mutlak hale getirir; skill tarafı cwd'ye güvenmek zorunda kalmasın.
+fn expand(permission: &Permission) -> String {
+    if !permission.kind.starts_with("fs.") || permission.item == "*" {
+        return permission.to_string();
+    }
+    format!("{}:{}", permission.kind, permission.host_path().display())
+}
+
+/// Hiçbir skill'in okuyup yazamayacağı yollar: vault'un yedek unlock anahtarları, API
+/// anahtarlı .env, şifreli veri anahtarı ve skill'in kendine izin yazabileceği config dosyaları.
+/// `fs.read:~` gibi geniş bir izin bunları kapsasa da açılmazlar.
+pub fn protected() -> Vec<PathBuf> {
+    let root = std::env::current_dir().unwrap_or_default();
+    let mut paths = vec![vault::keyring_dir(), root.join(".env"), root.join("memory/vault.json")];
+    paths.extend(config::layer_files().into_iter().map(|(_, path)| path));
+    paths
+}
+
+/// oryxis_skill_configure ve WASM sandbox'ı için mutlak yollu liste
+pub fn effective_strings(manifest: &SkillManifest) -> Vec<String> {
+    let permissions = effective(manifest);
+    let mut strings: Vec<String> = permissions.iter().map(expand).collect();
+    // Vault açıkken hafıza dosyalarının açık kopyaları proje dışında durur
+    strings.extend(vault::mirror_grants(&permissions));
+    strings.extend(protected().iter().map(|p| format!("fs.deny:{}", p.display())));
+    strings
+}
+
+fn find_manifest(skill: &str) -> Result<SkillManifest, OryxisError> {
+    skill_manifest::load_all(&skill_manifest::skills_dir())?
+        .into_iter()
+        .filter_map(|(_, m)| m.ok())
+        .find(|m| m.name == skill)
+        .ok_or_else(|| OryxisError::SkillNotFound(skill.to_string()))
+}
+
+/// `oryxis skills grant <skill> <permission>`
+pub fn grant(skill: &str, permission: &str) -> Result<(), OryxisError> {
+    find_manifest(skill)?;
+    parse(permission)?;
+    let mut entry = load_grants().remove(skill).unwrap_or_default();
+    entry.revoke.retain(|r| r != permission);
+    if !entry.grant.iter().any(|g| g == permission) {
+        entry.grant.push(permission.to_string());
+    }
+    let path = save_grants(skill, &entry)?;
+    println!("granted {} to {} ({})", permission, skill, path.display());
+    Ok(())
+}
+
+/// `oryxis skills revoke <skill> <permission>` — `fs.write` gibi türün tamamı da olabilir
+pub fn revoke(skill: &str, permission: &str) -> Result<(), OryxisError> {
+    find_manifest(skill)?;
+    if !KINDS.contains(&permission.trim()) {
+        parse(permission)?;
+    }
+    let mut entry = load_grants().remove(skill).unwrap_or_default();
+    entry.grant.retain(|g| g != permission);
+    if !entry.revoke.iter().any(|r| r == permission) {
+        entry.revoke.push(permission.to_string());
+    }
+    let path = save_grants(skill, &entry)?;
+    println!("revoked {} from {} ({})", permission, skill, path.display());
+    Ok(())
+}
+
+/// `oryxis skills permissions [skill]`
+pub fn show(skill: Option<&str>) -> Result<(), OryxisError> {
+    let manifests: Vec<SkillManifest> = skill_manifest::load_all(&skill_manifest::skills_dir())?
+        .into_iter()
+        .filter_map(|(_, m)| m.ok())
+        .filter(|m| skill.is_none_or(|s| s == m.name))
+        .collect();
+    if let Some(s) = skill
+        && manifests.is_empty()
+    {
+        return Err(OryxisError::SkillNotFound(s.to_string()));
+    }
+
+    for manifest in &manifests {
+        println!("{}", manifest.name);
+        let permissions = effective(manifest);
+        if permissions.is_empty() {
+            println!("  (none)");
+        }
+        for permission in permissions {
+            println!("  {}", permission);
+        }
+    }
+    println!("\nChange with `oryxis skills grant|revoke <skill> <permission>` ([permissions.<skill>] in {})", config::project_file().display());
+    Ok(())
+}
@@ -0,0 +1,339 @@
+// Native skill kütüphaneleri (skill_abi) — ctypes yerine host tarafında yüklenir.
+// Her kütüphane: abi_version → describe → invoke/free. Sonuç string'lerini
+// kütüphanenin kendi `oryxis_skill_free`'si serbest bırakır, sızıntı yok.
+// .wasm entrypoint'ler aynı arayüzle wasm_skills sandbox'ında çalışır.
+//
+// Hot reload (skill_watch): kayıtlar Arc ile tutulur, invoke Arc'ı klonlayıp kilidi
+// bırakır. Yeniden yüklenen skill'in eski kütüphanesi son çağrı bitince düşer.
+// Native kütüphaneler .oryxis/shadow altındaki kopyadan açılır; orijinal dosya
+// yüklüyken yeniden derlenebilir (Windows kilidi, Linux'ta dlopen aynı inode'u paylaşır).
+
+use libloading::Library;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+
+use skill_abi::{AbiVersionFn, ConfigureFn, DescribeFn, FreeFn, InvokeFn};
+
+use crate::errors::OryxisError;
+use crate::permissions;
+use crate::skill_manifest::{self, ParamType, SkillManifest};
+use crate::wasm_skills::WasmSkill;
+
+// Manifest'i olmayan, host ile gelen kütüphaneler (skill index araması)
+const BUILTIN: &[(&str, &str)] = &[("skill_lib", "skills/dll/skill_lib.dll")];
+
+/// Built-in'lerin izinleri; skill_lib index'i ve skill YAML'larını okur
+fn builtin_manifest(name: &str) -> SkillManifest {
+    let permissions = match name {
+        "skill_lib" => vec!["fs.read:skills,memory".to_string()],
+        _ => Vec::new(),
+    };
+    SkillManifest { name: name.to_string(), permissions, ..Default::default() }
+}
+
+/// Manifest'siz kütüphaneyi yükler, izinlerini iletir ve kaydeder
+fn load_builtin(name: &str, path: &Path) -> Result<(), OryxisError> {
+    let skill = load_library(path)?;
+    configure(&skill, &builtin_manifest(name))?;
+    register(name, skill);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FunctionInfo {
+    pub name: String,
+    #[serde(default)]
+    pub params: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Description {
+    pub name: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub functions: Vec<FunctionInfo>,
+}
+
+enum Backend {
+    Native {
+        invoke: InvokeFn,
+        free: FreeFn,
+        /// Eski kütüphanelerde yok → izinler uygulanamaz
+        configure: Option<ConfigureFn>,
+        // fn pointer'lar geçerli kalsın diye en son drop edilir
+        _lib: Library,
+    },
+    Wasm(WasmSkill),
+}
+
+pub struct LoadedSkill {
+    pub path: PathBuf,
+    pub description: Description,
+    /// Built-in kütüphanelerde yok; docstring ve tipler buradan gelir
+    pub manifest: Option<SkillManifest>,
+    backend: Backend,
+}
+
+static PLUGINS: OnceLock<RwLock<BTreeMap<String, Arc<LoadedSkill>>>> = OnceLock::new();
+static SHADOW_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn registry() -> &'static RwLock<BTreeMap<String, Arc<LoadedSkill>>> {
+    PLUGINS.get_or_init(|| RwLock::new(BTreeMap::new()))
+}
+
+fn shadow_dir() -> PathBuf {
+    std::env::current_dir().unwrap_or_default().join(".oryxis/shadow")
+}
+
+/// Kütüphaneyi benzersiz isimli bir kopyaya alır; her yükleme yeni bir image olur.
+fn shadow_copy(path: &Path) -> Result<PathBuf, OryxisError> {
+    std::fs::create_dir_all(shadow_dir()).map_err(|e| plugin_err(&shadow_dir(), e))?;
+    let stem = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let ext = path.extension().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    let n = SHADOW_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let shadow = shadow_dir().join(format!("{}-{}-{}.{}", stem, std::process::id(), n, ext));
+    std::fs::copy(path, &shadow).map_err(|e| plugin_err(path, e))?;
+    Ok(shadow)
+}
+
+fn plugin_err(path: &Path, msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::PluginError(format!("{}: {}", path.display(), msg))
+}
+
+/// Kütüphane string'ini kopyalar ve kütüphaneye geri verir.
+unsafe fn take_string(ptr: *mut c_char, free: FreeFn) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let s = unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned();
+    unsafe { free(ptr) };
+    Some(s)
+}
+
+pub fn load_library(path: &Path) -> Result<LoadedSkill, OryxisError> {
+    unsafe {
+        let shadow = shadow_copy(path)?;
+        let lib = Library::new(&shadow).map_err(|e| plugin_err(path, e));
+        // Unix'te açık image dosya silinince de geçerli; Windows'takiler startup'ta temizlenir
+        if cfg!(unix) {
+            let _ = std::fs::remove_file(&shadow);
+        }
+        let lib = lib?;
+
+        let abi_version = *lib
+            .get::<AbiVersionFn>(skill_abi::SYM_ABI_VERSION)
+            .map_err(|_| plugin_err(path, "not an Oryxis skill (missing oryxis_skill_abi_version)"))?;
+        let version = abi_version();
+        if version != skill_abi::ABI_VERSION {
+            return Err(plugin_err(path, format!(
+                "skill ABI v{}, host expects v{}", version, skill_abi::ABI_VERSION
+            )));
+        }
+
+        let describe = *lib.get::<DescribeFn>(skill_abi::SYM_DESCRIBE).map_err(|e| plugin_err(path, e))?;
+        let invoke = *lib.get::<InvokeFn>(skill_abi::SYM_INVOKE).map_err(|e| plugin_err(path, e))?;
+        let free = *lib.get::<FreeFn>(skill_abi::SYM_FREE).map_err(|e| plugin_err(path, e))?;
+        let configure = lib.get::<ConfigureFn>(skill_abi::SYM_CONFIGURE).ok().map(|f| *f);
+
+        let raw = take_string(describe(), free).ok_or_else(|| plugin_err(path, "describe returned null"))?;
+        let description: Description = serde_json::from_str(&raw).map_err(|e| plugin_err(path, e))?;
+
+        Ok(LoadedSkill {
+            path: path.to_path_buf(),
+            description,
+            manifest: None,
+            backend: Backend::Native { invoke, free, configure, _lib: lib },
+        })
+    }
+}
+
+/// Etkin izinleri native kütüphaneye iletir (WASM'da sandbox yüklemede kurulur).
+fn configure(skill: &LoadedSkill, manifest: &SkillManifest) -> Result<(), OryxisError> {
+    let Backend::Native { configure, .. } = &skill.backend else { return Ok(()) };
+    let Some(configure) = configure else {
+        return Err(plugin_err(&skill.path, "library has no oryxis_skill_configure, permissions cannot be enforced"));
+    };
+    let grants = serde_json::to_string(&permissions::effective_strings(manifest))
+        .map_err(|e| OryxisError::JsonParseError(e.to_string()))?;
+    let grants = CString::new(grants).map_err(|e| OryxisError::PluginError(e.to_string()))?;
+    unsafe { configure(grants.as_ptr()) };
+    Ok(())
+}
+
+/// Sandbox etkin izinlerden (manifest + kullanıcı grant/revoke) kurulur.
+pub fn load_wasm(path: &Path, manifest: &SkillManifest) -> Result<LoadedSkill, OryxisError> {
+    let skill = WasmSkill::load(path, &permissions::effective(manifest))?;
+    let description: Description = serde_json::from_str(&skill.describe()?).map_err(|e| plugin_err(path, e))?;
+    Ok(LoadedSkill {
+        path: path.to_path_buf(),
+        description,
+        manifest: Some(manifest.clone()),
+        backend: Backend::Wasm(skill),
+    })
+}
+
+/// Entrypoint uzantısına göre native veya wasm yükler; Python entrypoint'ler için None.
+pub fn load_entrypoint(path: &Path, manifest: &SkillManifest) -> Option<Result<LoadedSkill, OryxisError>> {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("wasm") => Some(load_wasm(path, manifest)),
+        Some(ext) if ext == skill_manifest::native_lib_extension() => Some(load_library(path).and_then(|mut skill| {
+            configure(&skill, manifest)?;
+            skill.manifest = Some(manifest.clone());
+            Ok(skill)
+        })),
+        _ => None,
+    }
+}
+
+fn register(name: &str, skill: LoadedSkill) {
+    registry().write().unwrap_or_else(|e| e.into_inner()).insert(name.to_string(), Arc::new(skill));
+}
+
+/// Kaydı siler; kütüphane, devam eden çağrılar bitince kapanır. Kayıtlı değilse false.
+pub fn unload(name: &str) -> bool {
+    registry().write().unwrap_or_else(|e| e.into_inner()).remove(name).is_some()
+}
+
+/// Bir manifest'in entrypoint'ini yükleyip kaydeder. Kütüphane yoksa veya
+/// Python entrypoint'se None (skill_index zaten uyarıyor).
+fn load_manifest(manifest: &SkillManifest) -> Option<Result<(), OryxisError>> {
+    let path = manifest.entrypoint.as_deref().and_then(skill_manifest::resolve_library)?;
+    let loaded = load_entrypoint(&path, manifest)?;
+    Some(loaded.map(|skill| register(&manifest.name, skill)))
+}
+
+/// skills/*.yaml içindeki native ve wasm entrypoint'leri yükler. Manifest adıyla kaydedilir.
+/// .py/.pyd entrypoint'ler ve eksik kütüphaneler atlanır.
+pub fn load_all() -> Vec<(String, Result<(), OryxisError>)> {
+    let mut results = Vec::new();
+
+    for (name, entrypoint) in BUILTIN {
+        let Some(path) = skill_manifest::resolve_library(entrypoint) else { continue };
+        results.push((name.to_string(), load_builtin(name, &path)));
+    }
+
+    let manifests = match skill_manifest::load_all(&skill_manifest::skills_dir()) {
+        Ok(m) => m,
+        Err(e) => return vec![("skills".to_string(), Err(e))],
+    };
+
+    for (_, manifest) in manifests {
+        let Ok(manifest) = manifest else { continue };
+        if let Some(result) = load_manifest(&manifest) {
+            results.push((manifest.name.clone(), result));
+        }
+    }
+    results
+}
+
+/// Tek skill'i diskten yeniden yükler (manifest + kütüphane). Manifest veya kütüphane
+/// artık yoksa kayıt kaldırılır. Dönen değer: skill şu an yüklü mü.
+pub fn reload(name: &str) -> Result<bool, OryxisError> {
+    if let Some((_, entrypoint)) = BUILTIN.iter().find(|(n, _)| *n == name) {
+        unload(name);
+        let Some(path) = skill_manifest::resolve_library(entrypoint) else { return Ok(false) };
+        load_builtin(name, &path)?;
+        return Ok(true);
+    }
+
+    let manifest = skill_manifest::load_all(&skill_manifest::skills_dir())?
+        .into_iter()
+        .filter_map(|(_, m)| m.ok())
+        .find(|m| m.name == name);
+    // Eskisi yenisi yüklenmeden düşer: hatalı bir build eski sürümle çalışmaya devam etmesin
+    unload(name);
+    match manifest.as_ref().and_then(load_manifest) {
+        Some(result) => result.map(|_| true),
+        None => Ok(false),
+    }
+}
+
+/// Native skill'in izinlerini yeniden iletir. Göreli grant'lar (`.`, `memory`) o anki
+/// çalışma klasörüne göre çözülür; skill test sandbox'ı bunu kullanır.
+pub fn reconfigure(name: &str) -> Result<(), OryxisError> {
+    let Some(skill) = registry().read().unwrap_or_else(|e| e.into_inner()).get(name).cloned() else { return Ok(()) };
+    match &skill.manifest {
+        Some(manifest) => configure(&skill, manifest),
+        None => configure(&skill, &builtin_manifest(name)),
+    }
+}
+
+/// Startup: eski shadow kopyaları temizler, yükleme hataları loglanır, chat durmaz.
+pub fn load_on_startup() {
+    let _ = std::fs::remove_dir_all(shadow_dir());
+    for (name, result) in load_all() {
+        if let Err(e) = result {
+            eprintln!("[PLUGINS] {}: {}", name, e);
+        }
+    }
+}
+
+/// Manifest'siz kütüphaneler: (isim, entrypoint)
+pub fn builtins() -> &'static [(&'static str, &'static str)] {
+    BUILTIN
+}
+
+/// `skill.function(*args)` — JSON argümanlar, JSON sonuç.
+pub fn invoke(skill: &str, function: &str, args: &[Value]) -> Result<Value, OryxisError> {
+    // Kilit çağrı boyunca tutulmaz; reload beklemez, eski kütüphane bu Arc bitince kapanır
+    let plugin = registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(skill)
+        .cloned()
+        .ok_or_else(|| OryxisError::SkillNotFound(skill.to_string()))?;
+
+    let args_json = Value::Array(args.to_vec()).to_string();
+    let raw = match &plugin.backend {
+        Backend::Native { invoke, free, .. } => {
+            let c_function = CString::new(function).map_err(|e| OryxisError::PluginError(e.to_string()))?;
+            let c_args = CString::new(args_json).map_err(|e| OryxisError::PluginError(e.to_string()))?;
+            unsafe { take_string(invoke(c_function.as_ptr(), c_args.as_ptr()), *free) }
+                .ok_or_else(|| OryxisError::PluginError(format!("{}.{} returned null", skill, function)))?
+        }
+        Backend::Wasm(wasm) => wasm.invoke(function, &args_json)?,
+    };
+
+    let mut envelope: serde_json::Map<String, Value> =
+        serde_json::from_str(&raw).map_err(|e| OryxisError::JsonParseError(e.to_string()))?;
+    if let Some(err) = envelope.remove("error") {
+        let msg = err.as_str().map(|s| s.to_string()).unwrap_or_else(|| err.to_string());
+        if envelope.get("kind").and_then(|k| k.as_str()) == Some("permission") {
+            return Err(OryxisError::SecurityViolation(format!("{}.{}: {}", skill, function, msg)));
+        }
+        return Err(OryxisError::PluginError(format!("{}.{}: {}", skill, function, msg)));
+    }
+    Ok(envelope.remove("ok").unwrap_or(Value::Null))
+}
+
+/// Python modülü üretmek için: fonksiyonlar + (varsa) manifest
+pub fn snapshot() -> Vec<(String, Description, Option<SkillManifest>)> {
+    registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .map(|(name, p)| (name.clone(), p.description.clone(), p.manifest.clone()))
+        .collect()
+}
+
+/// Manifest'teki parametre tipleri; ability tanımlı değilse None
+pub fn param_types(skill: &str, function: &str) -> Option<Vec<(String, ParamType)>> {
+    let plugins = registry().read().unwrap_or_else(|e| e.into_inner());
+    let ability = plugins.get(skill)?.manifest.as_ref()?.ability_for(function)?;
+    Some(ability.parameters.iter().map(|p| (p.name.clone(), p.kind)).collect())
+}
+
+pub fn describe(skill: &str) -> Option<Description> {
+    registry().read().unwrap_or_else(|e| e.into_inner()).get(skill).map(|p| p.description.clone())
+}
+
+pub fn loaded() -> Vec<String> {
+    registry().read().unwrap_or_else(|e| e.into_inner()).keys().cloned().collect()
+}
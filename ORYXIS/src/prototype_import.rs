@@ -0,0 +1,399 @@
+// `oryxis import <rocksdb_dir> [--dry-run]` — llama prototipinin `memory.OryxisMemory('./mydb')`
+// verisini yeni biçimlere taşır (rust_tests/mydb gibi).
+//
+// Prototip SurrealDB'yi RocksDB üzerinde çalıştırıyordu: kayıtlar `/*ns\0*db\0*tablo\0*id`
+// anahtarlarında, revision'lı bincode ile saklı. Klasör rocksdb_reader ile okunur, kayıtlar
+// JSON'a çevrilir, sonra:
+//   - `code` alanı olan kayıtlar (save_skill/get_skill) skill'dir: kodda `def` varsa
+//     skills/lib/<ad>.py + skills/<ad>.yaml (entrypoint = wrapper = .py) olarak yazılır,
+//     yoksa ("SELF SKILL.", "Import from save_file") açıklamasıyla hafızaya not düşülür
+//   - diğer kayıtlar memorylib'e `remember` ile girer; source = "imported from prototype RocksDB <dir>"
+// Var olan skill'lere dokunulmaz; aynı key farklı değerle varsa `<key>_prototype` olarak eklenir.
+
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::errors::OryxisError;
+use crate::plugins;
+use crate::rocksdb_reader;
+use crate::skill_index;
+use crate::skill_manifest::{self, Ability, Parameter, ParamType, Severity, SkillManifest};
+
+const MEMORY_SKILL: &str = "memorylib";
+
+fn import_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::ImportError(msg.to_string())
+}
+
+/// Revision'lı bincode (varint kodlama) okuyucu
+struct Decoder<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], OryxisError> {
+        let end = self.pos.checked_add(n).filter(|end| *end <= self.data.len()).ok_or_else(|| import_err("record ends early"))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    // < 251 tek bayt; 251/252/253 → ardından u16/u32/u64
+    fn uint(&mut self) -> Result<u64, OryxisError> {
+        let b = self.bytes(1)?[0];
+        Ok(match b {
+            0..=250 => b as u64,
+            251 => u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()) as u64,
+            252 => u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()) as u64,
+            253 => u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()),
+            _ => return Err(import_err("integer is too large")),
+        })
+    }
+
+    fn int(&mut self) -> Result<i64, OryxisError> {
+        let n = self.uint()?;
+        Ok(((n >> 1) as i64) ^ -((n & 1) as i64))
+    }
+
+    fn len(&mut self) -> Result<usize, OryxisError> {
+        usize::try_from(self.uint()?).map_err(|_| import_err("length is too large"))
+    }
+
+    fn string(&mut self) -> Result<String, OryxisError> {
+        let n = self.len()?;
+        String::from_utf8(self.bytes(n)?.to_vec()).map_err(|_| import_err("string is not UTF-8"))
+    }
+
+    /// Revision'lı tipin başı: revision numarası atlanır
+    fn revision(&mut self) -> Result<(), OryxisError> {
+        self.uint().map(|_| ())
+    }
+
+    /// Kayıt id'si: 0 sayı, 1 metin
+    fn id(&mut self) -> Result<String, OryxisError> {
+        self.revision()?;
+        match self.uint()? {
+            0 => Ok(self.int()?.to_string()),
+            1 => self.string(),
+            other => Err(import_err(format!("record id type {} is not supported", other))),
+        }
+    }
+
+    /// SurrealDB `Value` → JSON. Thing "tablo:id" olur.
+    fn value(&mut self) -> Result<Value, OryxisError> {
+        self.revision()?;
+        Ok(match self.uint()? {
+            0 | 1 => Value::Null,
+            2 => json!(self.bytes(1)?[0] != 0),
+            3 => {
+                self.revision()?;
+                match self.uint()? {
+                    0 => json!(self.int()?),
+                    1 => json!(f64::from_le_bytes(self.bytes(8)?.try_into().unwrap())),
+                    other => return Err(import_err(format!("number type {} is not supported", other))),
+                }
+            }
+            4 => {
+                self.revision()?;
+                json!(self.string()?)
+            }
+            8 => {
+                self.revision()?;
+                let n = self.len()?;
+                Value::Array((0..n).map(|_| self.value()).collect::<Result<_, _>>()?)
+            }
+            9 => {
+                self.revision()?;
+                let n = self.len()?;
+                let mut map = Map::new();
+                for _ in 0..n {
+                    let key = self.string()?;
+                    map.insert(key, self.value()?);
+                }
+                Value::Object(map)
+            }
+            12 => {
+                self.revision()?;
+                let table = self.string()?;
+                json!(format!("{}:{}", table, self.id()?))
+            }
+            other => return Err(import_err(format!("value type {} is not supported", other))),
+        })
+    }
+}
+
+/// `/*ns\0*db\0*tablo\0*...` → tablo (kayıt anahtarı değilse None)
+fn record_table(key: &[u8]) -> Option<String> {
+    let mut rest = key.strip_prefix(b"/*")?;
+    let mut parts = Vec::new();
+    for _ in 0..3 {
+        let end = rest.iter().position(|b| *b == 0)?;
+        parts.push(&rest[..end]);
+        rest = &rest[end + 1..];
+        if parts.len() < 3 {
+            rest = rest.strip_prefix(b"*")?;
+        }
+    }
+    rest.strip_prefix(b"*")?;
+    String::from_utf8(parts[2].to_vec()).ok()
+}
+
+struct Record {
+    table: String,
+    id: String,
+    fields: Map<String, Value>,
+}
+
+fn read_records(dir: &Path) -> Result<(Vec<Record>, Vec<String>), OryxisError> {
+    let mut records = Vec::new();
+    let mut skipped = Vec::new();
+    for (key, value) in rocksdb_reader::read_all(dir)? {
+        let Some(table) = record_table(&key) else { continue };
+        match (Decoder { data: &value, pos: 0 }).value() {
+            Ok(Value::Object(mut fields)) => {
+                let id = match fields.remove("id") {
+                    Some(Value::String(thing)) => thing.split_once(':').map(|(_, id)| id.to_string()).unwrap_or(thing),
+                    _ => format!("{}", records.len() + 1),
+                };
+                records.push(Record { table, id, fields });
+            }
+            Ok(_) => skipped.push(format!("{}: not an object", String::from_utf8_lossy(&key))),
+            Err(e) => skipped.push(format!("{}: {}", String::from_utf8_lossy(&key), e)),
+        }
+    }
+    Ok((records, skipped))
+}
+
+fn field<'a>(record: &'a Record, name: &str) -> Option<&'a str> {
+    record.fields.get(name).and_then(|v| v.as_str())
+}
+
+/// lower_snake_case, modül adı olarak import edilebilir
+fn sanitize(name: &str) -> String {
+    let mut out: String = name.trim().to_lowercase().chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    while out.contains("__") {
+        out = out.replace("__", "_");
+    }
+    let out = out.trim_matches('_').to_string();
+    if out.chars().next().is_none_or(|c| c.is_ascii_digit()) {
+        format!("prototype_{}", out)
+    } else {
+        out
+    }
+}
+
+/// Prototip açıklamasındaki "TAGS: a, b" satırı
+fn tags_of(description: &str, name: &str) -> Vec<String> {
+    let tags: Vec<String> = description
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("TAGS:"))
+        .map(|t| t.split(',').map(|t| t.trim().to_string()).filter(|t| !t.is_empty()).collect())
+        .unwrap_or_default();
+    if tags.is_empty() { vec![name.to_string()] } else { tags }
+}
+
+/// Üst seviye public fonksiyonlar → ability'ler
+fn abilities_of(module: &str, code: &str) -> BTreeMap<String, Ability> {
+    let re = Regex::new(r"(?m)^def\s+([A-Za-z]\w*)\s*\(([^)]*)\)").unwrap();
+    re.captures_iter(code)
+        .map(|c| {
+            let args = c[2].trim();
+            let parameters = args
+                .split(',')
+                .map(str::trim)
+                .filter(|a| !a.is_empty() && !a.starts_with('*'))
+                .map(|a| {
+                    let name = a.split(['=', ':']).next().unwrap_or(a).trim().to_string();
+                    Parameter { name, kind: ParamType::Any, description: String::new(), optional: a.contains('=') }
+                })
+                .collect();
+            let ability = Ability {
+                description: format!("Imported from the prototype skill `{}`.", module),
+                usage: format!("{}.{}({})", module, &c[1], args),
+                parameters,
+                returns: None,
+            };
+            (c[1].to_string(), ability)
+        })
+        .collect()
+}
+
+enum Plan {
+    /// Çalıştırılabilir Python skill'i
+    Skill { name: String, manifest: Box<SkillManifest>, code: String },
+    Memory { key: String, value: String, category: String, importance: f64 },
+    Skip(String),
+}
+
+fn plan_skill(record: &Record, existing: &[String]) -> Plan {
+    let name = sanitize(field(record, "name").unwrap_or(&record.id));
+    let description = field(record, "description").unwrap_or_default().trim().to_string();
+    let code = field(record, "code").unwrap_or_default().to_string();
+    let abilities = abilities_of(&name, &code);
+
+    if abilities.is_empty() {
+        // Kod değil bir not (native skill'e ya da başka bir skill'e işaret)
+        return Plan::Memory {
+            key: format!("prototype_skill_{}", name),
+            value: format!("{} — {} (code: {})", name, description, code.trim()),
+            category: "skill".to_string(),
+            importance: 0.5,
+        };
+    }
+    if existing.contains(&name) || skill_manifest::skills_dir().join(format!("{}.yaml", name)).exists() {
+        return Plan::Skip(format!("skill `{}` already exists", name));
+    }
+
+    let file = format!("skills/lib/{}.py", name);
+    let manifest = Box::new(SkillManifest {
+        name: name.clone(),
+        description: if description.is_empty() { format!("Imported from the prototype skill `{}`.", name) } else { description.clone() },
+        version: "0.1.0".to_string(),
+        author: String::new(),
+        entrypoint: Some(file.clone()),
+        wrapper: Some(file),
+        tags: tags_of(&description, &name),
+        abilities,
+        examples: Vec::new(),
+        permissions: Vec::new(),
+        requirements: Vec::new(),
+        dependencies: BTreeMap::new(),
+    });
+    Plan::Skill { name, manifest, code }
+}
+
+fn plan_memory(record: &Record) -> Plan {
+    let key = sanitize(field(record, "key").map(str::to_string).unwrap_or_else(|| format!("{}_{}", record.table, record.id)).as_str());
+    let value = ["value", "content", "text", "fact"].iter().find_map(|f| field(record, f)).map(str::to_string).unwrap_or_else(|| {
+        let rest: Map<String, Value> = record.fields.iter().filter(|(k, _)| k.as_str() != "key").map(|(k, v)| (k.clone(), v.clone())).collect();
+        Value::Object(rest).to_string()
+    });
+    Plan::Memory {
+        key,
+        value,
+        category: field(record, "category").unwrap_or(&record.table).to_string(),
+        importance: record.fields.get("importance").and_then(|v| v.as_f64()).unwrap_or(0.5).clamp(0.0, 1.0),
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), OryxisError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", parent.display(), e)))?;
+    }
+    std::fs::write(path, content).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", path.display(), e)))
+}
+
+fn install_skill(name: &str, manifest: &SkillManifest, code: &str, dir: &Path) -> Result<(), OryxisError> {
+    let root = std::env::current_dir().unwrap_or_default();
+    let header = format!("# {}.py — imported from the prototype RocksDB {} by `oryxis import`\n\n", name, dir.display());
+    write_file(&root.join(format!("skills/lib/{}.py", name)), &format!("{}{}\n", header, code.trim()))?;
+
+    let yaml = serde_yaml::to_string(manifest).map_err(|e| import_err(format!("{}: {}", name, e)))?;
+    let path = skill_manifest::skills_dir().join(format!("{}.yaml", name));
+    write_file(&path, &yaml)?;
+    println!("  + skill {} (skills/{}.yaml, {} ability(ies))", name, name, manifest.abilities.len());
+
+    for issue in skill_manifest::validate(&path, manifest) {
+        match issue.severity {
+            Severity::Error => println!("      ❌ {}", issue.message),
+            Severity::Warning => println!("      ⚠️  {}", issue.message),
+        }
+    }
+    Ok(())
+}
+
+/// `oryxis import <dir> [--dry-run]`
+pub fn run(dir: &str, dry_run: bool) -> Result<(), OryxisError> {
+    let dir = Path::new(dir);
+    let (records, skipped) = read_records(dir)?;
+    if records.is_empty() && skipped.is_empty() {
+        println!("No records found in {}.", dir.display());
+        return Ok(());
+    }
+
+    let mut tables: BTreeMap<&str, usize> = BTreeMap::new();
+    for record in &records {
+        *tables.entry(record.table.as_str()).or_default() += 1;
+    }
+    let summary: Vec<String> = tables.iter().map(|(t, n)| format!("{} ({})", t, n)).collect();
+    println!("{}: {} record(s) — {}", dir.display(), records.len(), summary.join(", "));
+    for s in &skipped {
+        println!("  ⚠️  cannot decode {}", s);
+    }
+
+    let mut planned_skills = Vec::new();
+    let plans: Vec<Plan> = records
+        .iter()
+        .map(|record| {
+            let plan = if record.fields.contains_key("code") { plan_skill(record, &planned_skills) } else { plan_memory(record) };
+            if let Plan::Skill { name, .. } = &plan {
+                planned_skills.push(name.clone());
+            }
+            plan
+        })
+        .collect();
+    let has_memories = plans.iter().any(|p| matches!(p, Plan::Memory { .. }));
+
+    if dry_run {
+        for plan in &plans {
+            match plan {
+                Plan::Skill { name, manifest, .. } => println!("  would add skill {} ({} ability(ies))", name, manifest.abilities.len()),
+                Plan::Memory { key, category, .. } => println!("  would remember {} ({})", key, category),
+                Plan::Skip(why) => println!("  skip: {}", why),
+            }
+        }
+        return Ok(());
+    }
+
+    // Hafıza yazılamayacaksa hiçbir şey yazmadan dur
+    let mut stored: BTreeMap<String, String> = BTreeMap::new();
+    if has_memories {
+        if plugins::describe(MEMORY_SKILL).is_none() {
+            plugins::load_on_startup();
+        }
+        if plugins::describe(MEMORY_SKILL).is_none() {
+            return Err(OryxisError::MemoryError(format!("{} is not loaded, build libraries_opensource/memory_lib", MEMORY_SKILL)));
+        }
+        if let Value::Array(all) = plugins::invoke(MEMORY_SKILL, "list_all", &[Value::Null])? {
+            for entry in all {
+                stored.insert(entry["key"].as_str().unwrap_or_default().to_string(), entry["value"].as_str().unwrap_or_default().to_string());
+            }
+        }
+    }
+
+    let full = std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+    let source = json!(format!("imported from prototype RocksDB {}", full.display()));
+    let (mut skills, mut memories) = (0, 0);
+    for plan in plans {
+        match plan {
+            Plan::Skill { name, manifest, code } => {
+                install_skill(&name, &manifest, &code, dir)?;
+                skills += 1;
+            }
+            Plan::Memory { mut key, value, category, importance } => {
+                match stored.get(&key) {
+                    Some(v) if *v == value => {
+                        println!("  = {} already remembered", key);
+                        continue;
+                    }
+                    Some(_) => key = format!("{}_prototype", key),
+                    None => {}
+                }
+                plugins::invoke(MEMORY_SKILL, "remember", &[json!(key), json!(value), json!(category), json!(importance), source.clone()])?;
+                println!("  + memory {} ({})", key, category);
+                stored.insert(key, value);
+                memories += 1;
+            }
+            Plan::Skip(why) => println!("  skip: {}", why),
+        }
+    }
+
+    if skills > 0 {
+        skill_index::rebuild()?;
+    }
+    println!("Imported {} skill(s) and {} memory(ies).", skills, memories);
+    Ok(())
+}
@@ -0,0 +1,301 @@
+// Embedded `oryxis` Python modülü — native skill'lere ctypes olmadan erişim:
+//
+//   from oryxis.skills import file_lib
+//   file_lib.read_file("notes.txt")
+//
+//   import oryxis
+//   oryxis.invoke("file_lib", "read_file", "notes.txt")
+//   oryxis.describe("cmd_lib")
+//   oryxis.loaded()
+//
+// `oryxis.skills.<name>` modülleri yüklü kütüphanelerden ve skills/*.yaml'dan üretilir:
+// imzalar ve docstring'ler manifest'ten, tip kontrolü parametre tiplerinden gelir.
+
+use pyo3::exceptions::{PyLookupError, PyPermissionError, PyRuntimeError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyModule, PyString, PyTuple};
+use serde_json::Value;
+
+use crate::errors::OryxisError;
+use crate::plugins::{self, Description};
+use crate::skill_manifest::{ParamType, SkillManifest};
+
+pyo3::create_exception!(oryxis, SkillError, PyRuntimeError, "A native skill call failed.");
+
+fn to_json(py: Python<'_>, obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+    let json = py.import("json")?;
+    let kwargs = PyDict::new(py);
+    // pathlib.Path gibi değerler string olarak gitsin
+    kwargs.set_item("default", py.import("builtins")?.getattr("str")?)?;
+    let dumped: String = json.call_method("dumps", (obj,), Some(&kwargs))?.extract()?;
+    serde_json::from_str(&dumped).map_err(|e| PyRuntimeError::new_err(e.to_string()))
+}
+
+fn from_json(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    Ok(py.import("json")?.call_method1("loads", (value.to_string(),))?.unbind())
+}
+
+fn to_py_err(e: OryxisError) -> PyErr {
+    match e {
+        OryxisError::SkillNotFound(_) => PyLookupError::new_err(e.to_string()),
+        OryxisError::SecurityViolation(_) => PyPermissionError::new_err(e.to_string()),
+        _ => SkillError::new_err(e.to_string()),
+    }
+}
+
+fn type_error(function: &str, name: &str, expected: &str, obj: &Bound<'_, PyAny>) -> PyErr {
+    let got = obj.get_type().name().map(|n| n.to_string()).unwrap_or_default();
+    PyTypeError::new_err(format!("{}() argument `{}` must be {}, not {}", function, name, expected, got))
+}
+
+/// Python değerini manifest'teki tipe göre kontrol edip JSON'a çevirir.
+fn coerce(py: Python<'_>, function: &str, name: &str, kind: ParamType, obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+    match kind {
+        ParamType::String if obj.is_instance_of::<PyString>() => Ok(Value::String(obj.extract()?)),
+        ParamType::String => Err(type_error(function, name, "str", obj)),
+        ParamType::Path => {
+            let path: String = py
+                .import("os")?
+                .call_method1("fspath", (obj,))
+                .map_err(|_| type_error(function, name, "str or os.PathLike", obj))?
+                .extract()
+                .map_err(|_| type_error(function, name, "str or os.PathLike", obj))?;
+            Ok(Value::String(path))
+        }
+        ParamType::Int if obj.is_instance_of::<PyInt>() && !obj.is_instance_of::<PyBool>() => {
+            Ok(Value::from(obj.extract::<i64>()?))
+        }
+        ParamType::Int => Err(type_error(function, name, "int", obj)),
+        ParamType::Float if (obj.is_instance_of::<PyFloat>() || obj.is_instance_of::<PyInt>()) && !obj.is_instance_of::<PyBool>() => {
+            Ok(Value::from(obj.extract::<f64>()?))
+        }
+        ParamType::Float => Err(type_error(function, name, "float", obj)),
+        ParamType::Bool if obj.is_instance_of::<PyBool>() => Ok(Value::Bool(obj.extract()?)),
+        ParamType::Bool => Err(type_error(function, name, "bool", obj)),
+        ParamType::List if obj.is_instance_of::<PyList>() || obj.is_instance_of::<PyTuple>() => to_json(py, obj),
+        ParamType::List => Err(type_error(function, name, "list", obj)),
+        ParamType::Dict if obj.is_instance_of::<PyDict>() => to_json(py, obj),
+        ParamType::Dict => Err(type_error(function, name, "dict", obj)),
+        ParamType::Json | ParamType::Any => to_json(py, obj),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (skill, function, *args))]
+fn invoke(py: Python<'_>, skill: &str, function: &str, args: &Bound<'_, PyTuple>) -> PyResult<PyObject> {
+    let args: Vec<Bound<'_, PyAny>> = args.iter().collect();
+    call(py, skill, function, &args)
+}
+
+/// Üretilen modüllerin çağırdığı giriş: sondaki None'lar (verilmemiş optional
+/// parametreler) atılır, kalanlar manifest tiplerine göre kontrol edilir.
+#[pyfunction]
+fn _call(py: Python<'_>, skill: &str, function: &str, args: Vec<Bound<'_, PyAny>>) -> PyResult<PyObject> {
+    let mut args = args;
+    while args.last().is_some_and(|a| a.is_none()) {
+        args.pop();
+    }
+    call(py, skill, function, &args)
+}
+
+fn call(py: Python<'_>, skill: &str, function: &str, args: &[Bound<'_, PyAny>]) -> PyResult<PyObject> {
+    let types = plugins::param_types(skill, function).unwrap_or_default();
+    let qualified = format!("{}.{}", skill, function);
+
+    let mut json_args = Vec::with_capacity(args.len());
+    for (i, arg) in args.iter().enumerate() {
+        let value = match types.get(i) {
+            Some(_) if arg.is_none() => Value::Null,
+            Some((name, kind)) => coerce(py, &qualified, name, *kind, arg)?,
+            None => to_json(py, arg)?,
+        };
+        json_args.push(value);
+    }
+
+    // Skill çalışırken GIL'i bırak; uzun komutlar diğer Python thread'lerini kilitlemesin
+    let result = py.allow_threads(|| plugins::invoke(skill, function, &json_args)).map_err(to_py_err)?;
+    from_json(py, &result)
+}
+
+#[pyfunction]
+fn describe(py: Python<'_>, skill: &str) -> PyResult<PyObject> {
+    let desc = plugins::describe(skill).ok_or_else(|| PyLookupError::new_err(format!("Skill not found: {}", skill)))?;
+    let functions: Vec<Value> = desc
+        .functions
+        .iter()
+        .map(|f| serde_json::json!({ "name": f.name, "params": f.params }))
+        .collect();
+    from_json(py, &serde_json::json!({ "name": desc.name, "version": desc.version, "functions": functions }))
+}
+
+#[pyfunction]
+fn loaded() -> Vec<String> {
+    plugins::loaded()
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_') && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn py_str(s: &str) -> String {
+    // JSON string literal'i geçerli bir Python string literal'idir
+    serde_json::to_string(s).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+fn type_name(kind: ParamType) -> &'static str {
+    match kind {
+        ParamType::Any => "any",
+        ParamType::String => "str",
+        ParamType::Int => "int",
+        ParamType::Float => "float",
+        ParamType::Bool => "bool",
+        ParamType::List => "list",
+        ParamType::Dict => "dict",
+        ParamType::Path => "path",
+        ParamType::Json => "json",
+    }
+}
+
+/// Tek bir skill modülünün Python kaynağı: her fonksiyon gerçek bir imza ve
+/// YAML'dan docstring ile `_call`'a yönlenir (help() ve inspect düzgün çalışır).
+fn module_source(skill: &str, desc: &Description, manifest: Option<&SkillManifest>) -> String {
+    let mut src = String::new();
+    let mut names = Vec::new();
+
+    for function in &desc.functions {
+        if !is_identifier(&function.name) {
+            continue;
+        }
+        let ability = manifest.and_then(|m| m.ability_for(&function.name));
+
+        // (ad, tip, optional, açıklama) — manifest parametreleri geçersizse describe'a düş
+        let params: Vec<(String, Option<ParamType>, bool, String)> = match ability {
+            Some(a) if a.parameters.iter().all(|p| is_identifier(&p.name)) && !a.parameters.is_empty() => a
+                .parameters
+                .iter()
+                .map(|p| (p.name.clone(), Some(p.kind), p.optional, p.description.clone()))
+                .collect(),
+            _ => function
+                .params
+                .iter()
+                .filter(|p| is_identifier(p))
+                .map(|p| (p.clone(), None, false, String::new()))
+                .collect(),
+        };
+
+        // optional'dan sonra gelen zorunlu parametre Python'da geçersiz → o da None default alır
+        let mut seen_optional = false;
+        let signature: Vec<String> = params
+            .iter()
+            .map(|(name, _, optional, _)| {
+                seen_optional |= *optional;
+                if seen_optional { format!("{}=None", name) } else { name.clone() }
+            })
+            .collect();
+
+        let mut doc = String::new();
+        if let Some(a) = ability {
+            if !a.description.trim().is_empty() {
+                doc.push_str(a.description.trim());
+                doc.push_str("\n\n");
+            }
+            if !a.usage.is_empty() {
+                doc.push_str(&format!("Usage: {}\n", a.usage));
+            }
+        }
+        if !params.is_empty() {
+            doc.push_str("\nParameters:\n");
+            for (name, kind, optional, description) in &params {
+                let kind = kind.map(type_name).unwrap_or("any");
+                let opt = if *optional { ", optional" } else { "" };
+                if description.is_empty() {
+                    doc.push_str(&format!("    {} ({}{})\n", name, kind, opt));
+                } else {
+                    doc.push_str(&format!("    {} ({}{}): {}\n", name, kind, opt, description));
+                }
+            }
+        }
+        if let Some(returns) = ability.and_then(|a| a.returns) {
+            doc.push_str(&format!("\nReturns: {}\n", type_name(returns)));
+        }
+
+        let arg_list: Vec<&str> = params.iter().map(|(n, ..)| n.as_str()).collect();
+        src.push_str(&format!(
+            "def {name}({sig}):\n    {doc}\n    return _call({skill}, {name_lit}, [{args}])\n\n",
+            name = function.name,
+            sig = signature.join(", "),
+            doc = py_str(doc.trim_end()),
+            skill = py_str(skill),
+            name_lit = py_str(&function.name),
+            args = arg_list.join(", "),
+        ));
+        names.push(py_str(&function.name));
+    }
+
+    src.push_str(&format!("__all__ = [{}]\n", names.join(", ")));
+    src
+}
+
+/// `oryxis.skills` ve altındaki modülleri (yeniden) üretir ve sys.modules'a yazar.
+/// Kütüphaneler yeniden yüklendiğinde tekrar çağrılabilir.
+pub fn install_skills_module(py: Python<'_>, oryxis: &Bound<'_, PyModule>) -> PyResult<()> {
+    let sys_modules = py.import("sys")?.getattr("modules")?;
+    let call_fn = oryxis.getattr("_call")?;
+
+    // Eski alt modülleri temizle (kaldırılan skill import edilemesin)
+    let stale: Vec<String> = sys_modules
+        .call_method0("keys")?
+        .try_iter()?
+        .filter_map(|k| k.ok()?.extract::<String>().ok())
+        .filter(|k| k.starts_with("oryxis.skills."))
+        .collect();
+    for key in stale {
+        sys_modules.del_item(key)?;
+    }
+
+    let skills_mod = PyModule::new(py, "oryxis.skills")?;
+    skills_mod.setattr("__doc__", "Native Oryxis skills, generated from skills/*.yaml and the loaded libraries.")?;
+    let mut all = Vec::new();
+
+    for (name, desc, manifest) in plugins::snapshot() {
+        if !is_identifier(&name) {
+            continue;
+        }
+        let qualified = format!("oryxis.skills.{}", name);
+        let module = PyModule::new(py, &qualified)?;
+        let globals = module.dict();
+        globals.set_item("_call", &call_fn)?;
+
+        let source = module_source(&name, &desc, manifest.as_ref());
+        let code = std::ffi::CString::new(source).map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        py.run(&code, Some(&globals), None)?;
+
+        let doc = manifest
+            .as_ref()
+            .map(|m| m.description.split_whitespace().collect::<Vec<_>>().join(" "))
+            .unwrap_or_default();
+        module.setattr("__doc__", doc)?;
+        module.setattr("__version__", &desc.version)?;
+
+        sys_modules.set_item(&qualified, &module)?;
+        skills_mod.setattr(name.as_str(), &module)?;
+        all.push(name);
+    }
+
+    skills_mod.setattr("__all__", all)?;
+    sys_modules.set_item("oryxis.skills", &skills_mod)?;
+    oryxis.setattr("skills", &skills_mod)?;
+    Ok(())
+}
+
+#[pymodule]
+pub fn oryxis(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(invoke, m)?)?;
+    m.add_function(wrap_pyfunction!(_call, m)?)?;
+    m.add_function(wrap_pyfunction!(describe, m)?)?;
+    m.add_function(wrap_pyfunction!(loaded, m)?)?;
+    m.add("SkillError", m.py().get_type::<SkillError>())?;
+    install_skills_module(m.py(), m)?;
+    Ok(())
+}
@@ -0,0 +1,548 @@
+// Salt-okunur, saf Rust RocksDB okuyucu — prototipin `OryxisMemory('./mydb')` verisini
+// içe aktarmak için (librocksdb/libclang gerekmez).
+//
+// Desteklenen: MANIFEST (canlı SST'ler ve WAL numarası), WAL (WriteBatch put/delete/range delete),
+// block-based SST (format_version 2–6, sıkıştırmasız ya da Snappy). Yalnız default column family
+// okunur. Checksum'lar doğrulanmaz; merge operand'ları ve blob dosyaları desteklenmez.
+// MANIFEST okunamazsa klasördeki bütün *.sst ve *.log dosyaları kullanılır.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::errors::OryxisError;
+
+const TABLE_MAGIC: u64 = 0x88e241b785f4cff7;
+const LEGACY_TABLE_MAGIC: u64 = 0xdb4775248b80fb57;
+const FOOTER_LEN: usize = 53;
+const LEGACY_FOOTER_LEN: usize = 48;
+const BLOCK_TRAILER_LEN: usize = 5;
+const LOG_BLOCK_SIZE: usize = 32 * 1024;
+
+fn format_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::ImportError(msg.to_string())
+}
+
+/// Bayt dizisi üzerinde ilerleyen okuyucu
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Cursor { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], OryxisError> {
+        let end = self.pos.checked_add(n).filter(|end| *end <= self.data.len()).ok_or_else(|| format_err("unexpected end of data"))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, OryxisError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, OryxisError> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, OryxisError> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn varint(&mut self) -> Result<u64, OryxisError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.u8()?;
+            value |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(format_err("varint is too long"))
+    }
+
+    fn varint_usize(&mut self) -> Result<usize, OryxisError> {
+        usize::try_from(self.varint()?).map_err(|_| format_err("length does not fit in memory"))
+    }
+
+    /// varint uzunluk + baytlar
+    fn slice(&mut self) -> Result<&'a [u8], OryxisError> {
+        let n = self.varint_usize()?;
+        self.bytes(n)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct BlockHandle {
+    offset: u64,
+    size: u64,
+}
+
+impl BlockHandle {
+    fn decode(cur: &mut Cursor) -> Result<Self, OryxisError> {
+        Ok(BlockHandle { offset: cur.varint()?, size: cur.varint()? })
+    }
+}
+
+enum Op {
+    Put(Vec<u8>, Vec<u8>),
+    Delete(Vec<u8>),
+    /// [başlangıç, bitiş)
+    DeleteRange(Vec<u8>, Vec<u8>),
+}
+
+/// Sıra numarasıyla bir yazma; en büyük numaralı kazanır
+struct Entry {
+    seq: u64,
+    op: Op,
+}
+
+// Internal key: user key + 8 bayt (seq << 8 | tip)
+fn split_internal_key(key: &[u8]) -> Result<(&[u8], u64, u8), OryxisError> {
+    if key.len() < 8 {
+        return Err(format_err("internal key is too short"));
+    }
+    let (user, trailer) = key.split_at(key.len() - 8);
+    let packed = u64::from_le_bytes(trailer.try_into().unwrap());
+    Ok((user, packed >> 8, (packed & 0xff) as u8))
+}
+
+/// Block'un girdileri. `delta`: format_version ≥ 4 index block'u — değer uzunluğu yazılmaz,
+/// değer restart noktasında tam BlockHandle, sonrasında yalnız boyut farkıdır.
+fn parse_block<V>(
+    block: &[u8],
+    delta: bool,
+    mut value: impl FnMut(&mut Cursor, bool, Option<usize>) -> Result<V, OryxisError>,
+) -> Result<Vec<(Vec<u8>, V)>, OryxisError> {
+    if block.len() < 4 {
+        return Err(format_err("block is too short"));
+    }
+    // Son u32: restart sayısı (üst bit data block hash index'i)
+    let count = u32::from_le_bytes(block[block.len() - 4..].try_into().unwrap()) & 0x7fff_ffff;
+    let restarts_len = (count as usize + 1) * 4;
+    if restarts_len > block.len() {
+        return Err(format_err("corrupt block restart array"));
+    }
+    let body = block.len() - restarts_len;
+    let restarts: BTreeSet<usize> = block[body..block.len() - 4]
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes(c.try_into().unwrap()) as usize)
+        .collect();
+    let mut cur = Cursor::new(&block[..body]);
+
+    let mut entries = Vec::new();
+    let mut key: Vec<u8> = Vec::new();
+    while !cur.is_empty() {
+        let restart = restarts.contains(&cur.pos);
+        let shared = cur.varint_usize()?;
+        let non_shared = cur.varint_usize()?;
+        let value_len = if delta { None } else { Some(cur.varint_usize()?) };
+        if shared > key.len() {
+            return Err(format_err("corrupt block key prefix"));
+        }
+        key.truncate(shared);
+        key.extend_from_slice(cur.bytes(non_shared)?);
+        let v = value(&mut cur, restart, value_len)?;
+        entries.push((key.clone(), v));
+    }
+    Ok(entries)
+}
+
+/// (anahtar, değer) çiftleri
+type KeyValues = Vec<(Vec<u8>, Vec<u8>)>;
+
+/// Data/meta block girdileri
+fn block_entries(block: &[u8]) -> Result<KeyValues, OryxisError> {
+    parse_block(block, false, |cur, _, len| Ok(cur.bytes(len.unwrap_or_default())?.to_vec()))
+}
+
+/// Index block: data block handle'ları
+fn index_handles(block: &[u8], delta: bool) -> Result<Vec<BlockHandle>, OryxisError> {
+    let mut prev: Option<BlockHandle> = None;
+    let entries = parse_block(block, delta, |cur, restart, len| {
+        let handle = match (len, prev) {
+            (Some(len), _) => BlockHandle::decode(&mut Cursor::new(cur.bytes(len)?))?,
+            (None, Some(p)) if !restart => {
+                let raw = cur.varint()?;
+                let diff = ((raw >> 1) as i64) ^ -((raw & 1) as i64);
+                BlockHandle { offset: p.offset + p.size + BLOCK_TRAILER_LEN as u64, size: p.size.wrapping_add_signed(diff) }
+            }
+            (None, _) => BlockHandle::decode(cur)?,
+        };
+        prev = Some(handle);
+        Ok(handle)
+    })?;
+    Ok(entries.into_iter().map(|(_, h)| h).collect())
+}
+
+struct Table {
+    data: Vec<u8>,
+    path: PathBuf,
+}
+
+impl Table {
+    fn open(path: &Path) -> Result<Self, OryxisError> {
+        let data = std::fs::read(path).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", path.display(), e)))?;
+        Ok(Table { data, path: path.to_path_buf() })
+    }
+
+    fn err(&self, msg: impl std::fmt::Display) -> OryxisError {
+        format_err(format!("{}: {}", self.path.display(), msg))
+    }
+
+    /// Footer'dan (metaindex, index handle'ı; fv ≥ 6'da index metaindex'te)
+    fn footer(&self) -> Result<(BlockHandle, Option<BlockHandle>, u32), OryxisError> {
+        let len = self.data.len();
+        if len < LEGACY_FOOTER_LEN {
+            return Err(self.err("file is too short for a table footer"));
+        }
+        let magic = u64::from_le_bytes(self.data[len - 8..].try_into().unwrap());
+        if magic == LEGACY_TABLE_MAGIC {
+            let mut cur = Cursor::new(&self.data[len - LEGACY_FOOTER_LEN..]);
+            return Ok((BlockHandle::decode(&mut cur)?, Some(BlockHandle::decode(&mut cur)?), 0));
+        }
+        if magic != TABLE_MAGIC || len < FOOTER_LEN {
+            return Err(self.err("not a block-based table (bad magic)"));
+        }
+        let footer = &self.data[len - FOOTER_LEN..];
+        let version = u32::from_le_bytes(footer[FOOTER_LEN - 12..FOOTER_LEN - 8].try_into().unwrap());
+        if version >= 6 {
+            // checksum tipi, "extended magic", footer checksum, base context checksum, metaindex boyutu
+            let mut cur = Cursor::new(&footer[1..]);
+            if cur.bytes(4)? != [0x3e, 0x00, 0x7a, 0x00] {
+                return Err(self.err("bad extended footer magic"));
+            }
+            cur.bytes(8)?;
+            let size = cur.u32()? as u64;
+            let footer_start = (len - FOOTER_LEN) as u64;
+            let offset = footer_start
+                .checked_sub(size + BLOCK_TRAILER_LEN as u64)
+                .ok_or_else(|| self.err("metaindex block is out of range"))?;
+            return Ok((BlockHandle { offset, size }, None, version));
+        }
+        // fv 1–5: checksum tipi + metaindex + index handle
+        let mut cur = Cursor::new(&footer[1..]);
+        Ok((BlockHandle::decode(&mut cur)?, Some(BlockHandle::decode(&mut cur)?), version))
+    }
+
+    fn block(&self, handle: BlockHandle) -> Result<Vec<u8>, OryxisError> {
+        let start = usize::try_from(handle.offset).map_err(|_| self.err("block offset is out of range"))?;
+        let size = usize::try_from(handle.size).map_err(|_| self.err("block size is out of range"))?;
+        let end = start.checked_add(size + BLOCK_TRAILER_LEN).filter(|end| *end <= self.data.len()).ok_or_else(|| self.err("block is out of range"))?;
+        let raw = &self.data[start..start + size];
+        match self.data[end - BLOCK_TRAILER_LEN] {
+            0 => Ok(raw.to_vec()),
+            1 => snap::raw::Decoder::new().decompress_vec(raw).map_err(|e| self.err(format!("snappy: {}", e))),
+            other => Err(self.err(format!("compression type {} is not supported (only none and snappy)", other))),
+        }
+    }
+
+    fn entries(self, into: &mut Vec<Entry>) -> Result<(), OryxisError> {
+        let (metaindex, index, version) = self.footer()?;
+        let meta: BTreeMap<Vec<u8>, Vec<u8>> = block_entries(&self.block(metaindex)?)?.into_iter().collect();
+        let meta_handle = |name: &str| -> Result<Option<BlockHandle>, OryxisError> {
+            meta.get(name.as_bytes()).map(|v| BlockHandle::decode(&mut Cursor::new(v))).transpose()
+        };
+        let index = match index {
+            Some(index) => index,
+            None => meta_handle("rocksdb.index")?.ok_or_else(|| self.err("no index block"))?,
+        };
+
+        // Index anahtarları biçimi (user key mi, handle delta mı) properties'ten
+        let mut props = BTreeMap::new();
+        if let Some(handle) = meta_handle("rocksdb.properties")? {
+            props = block_entries(&self.block(handle)?)?.into_iter().collect();
+        }
+        let flag = |name: &str| props.get(name.as_bytes()).is_some_and(|v: &Vec<u8>| v.first().is_some_and(|b| *b != 0));
+        let value_delta = version >= 4 && flag("rocksdb.index.value.is.delta.encoded");
+        if props.get("rocksdb.index.type".as_bytes()).is_some_and(|v| Cursor::new(v).varint().ok() == Some(2)) {
+            return Err(self.err("partitioned indexes are not supported"));
+        }
+
+        for handle in index_handles(&self.block(index)?, value_delta)? {
+            for (key, value) in block_entries(&self.block(handle)?)? {
+                let (user, seq, kind) = split_internal_key(&key)?;
+                let op = match kind {
+                    1 => Op::Put(user.to_vec(), value),
+                    0 | 7 => Op::Delete(user.to_vec()),
+                    2 => return Err(self.err("merge operands are not supported")),
+                    _ => continue,
+                };
+                into.push(Entry { seq, op });
+            }
+        }
+
+        if let Some(handle) = meta_handle("rocksdb.range_del")? {
+            for (key, end) in block_entries(&self.block(handle)?)? {
+                let (start, seq, _) = split_internal_key(&key)?;
+                into.push(Entry { seq, op: Op::DeleteRange(start.to_vec(), end) });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Log (WAL/MANIFEST) dosyasındaki kayıtlar; parçalı kayıtlar birleştirilir
+fn log_records(path: &Path) -> Result<Vec<Vec<u8>>, OryxisError> {
+    let data = std::fs::read(path).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", path.display(), e)))?;
+    let mut records = Vec::new();
+    let mut pending: Option<Vec<u8>> = None;
+    let mut pos = 0;
+    while pos < data.len() {
+        let left_in_block = LOG_BLOCK_SIZE - pos % LOG_BLOCK_SIZE;
+        if left_in_block < 7 {
+            pos += left_in_block;
+            continue;
+        }
+        let mut cur = Cursor::new(&data[pos..]);
+        let header = (|| -> Result<(usize, u8), OryxisError> {
+            cur.u32()?;
+            let len = u16::from_le_bytes(cur.bytes(2)?.try_into().unwrap()) as usize;
+            Ok((len, cur.u8()?))
+        })();
+        let Ok((len, kind)) = header else { break };
+        // 5–8: recyclable kayıtlar, başlıkta ek 4 bayt log numarası
+        let header_len = if (5..=8).contains(&kind) { 11 } else { 7 };
+        if kind == 0 && len == 0 {
+            // Önceden ayrılmış, yazılmamış alan
+            pos += left_in_block;
+            continue;
+        }
+        let Some(payload) = data.get(pos + header_len..pos + header_len + len) else { break };
+        pos += header_len + len;
+        match kind {
+            1 | 5 => records.push(payload.to_vec()),
+            2 | 6 => pending = Some(payload.to_vec()),
+            3 | 7 => {
+                if let Some(p) = pending.as_mut() {
+                    p.extend_from_slice(payload)
+                }
+            }
+            4 | 8 => {
+                if let Some(mut p) = pending.take() {
+                    p.extend_from_slice(payload);
+                    records.push(p);
+                }
+            }
+            // Sıkıştırma/zaman damgası meta kayıtları
+            _ => {}
+        }
+    }
+    Ok(records)
+}
+
+/// WAL'daki WriteBatch'ler (yalnız default column family)
+fn wal_entries(path: &Path, into: &mut Vec<Entry>) -> Result<(), OryxisError> {
+    let err = |msg: &str| format_err(format!("{}: {}", path.display(), msg));
+    for record in log_records(path)? {
+        let mut cur = Cursor::new(&record);
+        let Ok(mut seq) = cur.u64() else { continue };
+        let _count = cur.u32()?;
+        while !cur.is_empty() {
+            let tag = cur.u8()?;
+            let cf = if matches!(tag, 4 | 5 | 6 | 8 | 0xe | 0x10 | 0x16) { cur.varint()? } else { 0 };
+            let op = match tag {
+                1 | 5 => Some(Op::Put(cur.slice()?.to_vec(), cur.slice()?.to_vec())),
+                0 | 4 | 7 | 8 => Some(Op::Delete(cur.slice()?.to_vec())),
+                0xe | 0xf => Some(Op::DeleteRange(cur.slice()?.to_vec(), cur.slice()?.to_vec())),
+                2 | 6 => return Err(err("merge operands are not supported")),
+                0x10 | 0x11 => return Err(err("blob files are not supported")),
+                // LogData, 2PC işaretleri, noop
+                3 | 0xa | 0xb | 0xc => {
+                    cur.slice()?;
+                    None
+                }
+                9 | 0xd | 0x12 | 0x13 => None,
+                other => return Err(err(&format!("unknown write batch record {:#x}", other))),
+            };
+            if let Some(op) = op {
+                if cf == 0 {
+                    into.push(Entry { seq, op });
+                }
+                seq += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// MANIFEST'ten default column family'nin canlı SST numaraları ve WAL alt sınırı
+fn live_files(dir: &Path) -> Result<(BTreeSet<u64>, u64), OryxisError> {
+    let current = std::fs::read_to_string(dir.join("CURRENT")).map_err(|e| format_err(format!("CURRENT: {}", e)))?;
+    let manifest = dir.join(current.trim());
+
+    let mut files = BTreeSet::new();
+    let mut log_number = 0;
+    for record in log_records(&manifest)? {
+        let mut cur = Cursor::new(&record);
+        let mut cf = 0;
+        let mut added = Vec::new();
+        let mut deleted = Vec::new();
+        let mut edit_log = None;
+        while !cur.is_empty() {
+            let tag = cur.varint()?;
+            match tag {
+                1 | 201 => {
+                    cur.slice()?;
+                }
+                2 => edit_log = Some(cur.varint()?),
+                3 | 4 | 9 | 10 | 203 | 300 => {
+                    cur.varint()?;
+                }
+                5 => {
+                    cur.varint()?;
+                    cur.slice()?;
+                }
+                6 => {
+                    cur.varint()?;
+                    deleted.push(cur.varint()?);
+                }
+                7 | 100 | 102 | 103 => {
+                    cur.varint()?;
+                    added.push(cur.varint()?);
+                    if tag == 102 {
+                        cur.varint()?;
+                    }
+                    cur.varint()?;
+                    cur.slice()?;
+                    cur.slice()?;
+                    if tag != 7 {
+                        cur.varint()?;
+                        cur.varint()?;
+                    }
+                    if tag == 103 {
+                        // Özel alanlar: (tag, değer) ... 1
+                        while cur.varint()? != 1 {
+                            cur.slice()?;
+                        }
+                    }
+                }
+                200 => cf = cur.varint()?,
+                202 => {}
+                // kTagSafeIgnoreMask: uzunluklu, atlanabilir
+                t if t & (1 << 13) != 0 => {
+                    cur.slice()?;
+                }
+                other => return Err(format_err(format!("{}: unknown version edit tag {}", manifest.display(), other))),
+            }
+        }
+        if cf != 0 {
+            continue;
+        }
+        files.extend(added);
+        for number in deleted {
+            files.remove(&number);
+        }
+        if let Some(n) = edit_log {
+            log_number = n;
+        }
+    }
+    Ok((files, log_number))
+}
+
+/// "000224.sst" → 224
+fn file_number(path: &Path, ext: &str) -> Option<u64> {
+    if path.extension()? != ext {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+/// Klasördeki veritabanının güncel içeriği: user key → değer
+pub fn read_all(dir: &Path) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, OryxisError> {
+    if !dir.join("CURRENT").is_file() {
+        return Err(format_err(format!("{} is not a RocksDB directory (no CURRENT file)", dir.display())));
+    }
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| OryxisError::FileOperationError(format!("{}: {}", dir.display(), e)))?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .collect();
+    paths.sort();
+
+    let live = match live_files(dir) {
+        Ok(live) => Some(live),
+        Err(e) => {
+            eprintln!("[IMPORT] {} — reading every table and log file", e);
+            None
+        }
+    };
+
+    let mut entries = Vec::new();
+    for path in &paths {
+        let (sst, log) = (file_number(path, "sst"), file_number(path, "log"));
+        if sst.is_some_and(|n| live.as_ref().is_none_or(|(files, _)| files.contains(&n))) {
+            Table::open(path)?.entries(&mut entries)?;
+        } else if log.is_some_and(|n| live.as_ref().is_none_or(|(_, log_number)| n >= *log_number)) {
+            wal_entries(path, &mut entries)?;
+        }
+    }
+
+    // Eski yazmalar önce; aynı numarada SST'ler WAL'dan önce okunmuştu
+    entries.sort_by_key(|e| e.seq);
+    let mut db = BTreeMap::new();
+    for entry in entries {
+        match entry.op {
+            Op::Put(key, value) => {
+                db.insert(key, value);
+            }
+            Op::Delete(key) => {
+                db.remove(&key);
+            }
+            Op::DeleteRange(start, end) if start < end => {
+                let doomed: Vec<Vec<u8>> = db.range(start..end).map(|(k, _)| k.clone()).collect();
+                for key in doomed {
+                    db.remove(&key);
+                }
+            }
+            Op::DeleteRange(..) => {}
+        }
+    }
+    Ok(db)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mydb() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../rust_tests/mydb")
+    }
+
+    fn skill_key(name: &str) -> Vec<u8> {
+        [b"/*oryxis\0*skills\0*skills\0*\0\0\0\x01".as_slice(), name.as_bytes(), b"\0"].concat()
+    }
+
+    fn contains(haystack: &[u8], needle: &str) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle.as_bytes())
+    }
+
+    #[test]
+    fn reads_prototype_database() {
+        let db = read_all(&mydb()).unwrap();
+        assert_eq!(db.len(), 9);
+
+        let metadata: [&[u8]; 4] = [b"!v", b"/!nsoryxis\0", b"/*oryxis\0!dbskills\0", b"/*oryxis\0*skills\0!tbskills\0"];
+        for key in metadata {
+            assert!(db.contains_key(key), "missing {:?}", String::from_utf8_lossy(key));
+        }
+        assert_eq!(db.keys().filter(|k| k.starts_with(b"/!nd")).count(), 1);
+
+        let skills = [("cmd", "SELF SKILL."), ("save_file", "def save_file("), ("save_doc", "Skill for saving DOC files"), ("save_pdf", "Skill for saving PDF files")];
+        for (name, text) in skills {
+            let value = db.get(&skill_key(name)).unwrap_or_else(|| panic!("missing skill {}", name));
+            assert!(contains(value, text), "{}: value lacks {:?}", name, text);
+        }
+    }
+
+    #[test]
+    fn rejects_folder_without_current() {
+        assert!(read_all(&mydb().join("missing")).is_err());
+    }
+}
@@ -4,11 +4,27 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "snake_case")]
 pub enum ActionType {
     Execute,
+    FastExecute,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    #[serde(alias = "py")]
+    Python,
+    #[serde(alias = "sh", alias = "bash")]
+    Shell,
+    #[serde(alias = "js")]
+    Javascript,
+    Lua,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ScriptResponse {
     pub action: ActionType,
+    #[serde(default)]
+    pub language: Language,
     pub code: String,
 }
 
@@ -0,0 +1,121 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::process::{Child, Command};
+use tokio::task::JoinHandle;
+
+/// Shell çıktıktan sonra pipe'larda kalanı okumak için beklenen süre. Arka planda
+/// kalan bir torun (`sleep 100 &`) pipe'ı açık tutsa bile sonuç bundan fazla gecikmez.
+const DRAIN_GRACE: Duration = Duration::from_millis(200);
+
+type Buffer = Arc<Mutex<Vec<u8>>>;
+
+/// Pipe'ı EOF'a ya da iptale kadar ortak buffer'a okur; beklemeyi bırakınca o ana kadarki çıktı kalır
+fn collect(mut pipe: impl AsyncRead + Unpin + Send + 'static) -> (Buffer, JoinHandle<()>) {
+    let buffer = Buffer::default();
+    let sink = buffer.clone();
+    let task = tokio::spawn(async move {
+        let mut chunk = [0u8; 8192];
+        while let Ok(n) = pipe.read(&mut chunk).await {
+            if n == 0 {
+                break;
+            }
+            sink.lock().unwrap().extend_from_slice(&chunk[..n]);
+        }
+    });
+    (buffer, task)
+}
+
+/// Shell'i ve başlattığı her şeyi (pipeline'lar, arka plan işleri) öldürür
+fn kill_tree(child: &mut Child) {
+    #[cfg(unix)]
+    if let Some(pid) = child.id() {
+        // Kendi process group'unda başladı: pgid = pid
+        unsafe {
+            libc::killpg(pid as libc::pid_t, libc::SIGKILL);
+        }
+    }
+    #[cfg(windows)]
+    if let Some(pid) = child.id() {
+        let _ = std::process::Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+    let _ = child.start_kill();
+}
+
+/// POSIX shell (Windows'ta cmd) üzerinden kod çalıştırır.
+/// Python executer ile aynı çalışma dizininde, verilen timeout ile. Skill izinleri
+/// (`permissions`) shell'e uygulanmaz; shell kullanıcının yetkileriyle çalışır.
+pub async fn handle_shell_execute(code: String, timeout: Duration) -> String {
+    #[cfg(windows)]
+    let mut command = {
+        let mut c = Command::new("cmd");
+        c.args(["/C", code.trim()]);
+        c
+    };
+    #[cfg(not(windows))]
+    let mut command = {
+        let mut c = Command::new("sh");
+        c.args(["-c", code.trim()]);
+        // Timeout'ta tüm grup tek seferde öldürülebilsin
+        c.process_group(0);
+        c
+    };
+
+    let mut child = match command
+        .current_dir(std::env::current_dir().unwrap_or_default())
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(c) => c,
+        Err(e) => return format!("Shell Error: {}", e),
+    };
+
+    let (stdout_buf, stdout_task) = collect(child.stdout.take().expect("stdout is piped"));
+    let (stderr_buf, stderr_task) = collect(child.stderr.take().expect("stderr is piped"));
+
+    let status = match tokio::time::timeout(timeout, child.wait()).await {
+        Ok(Ok(status)) => status,
+        Ok(Err(e)) => {
+            kill_tree(&mut child);
+            return format!("Shell Error: {}", e);
+        }
+        Err(_) => {
+            kill_tree(&mut child);
+            let _ = child.wait().await;
+            stdout_task.abort();
+            stderr_task.abort();
+            return format!("Shell Error: timed out after {}s", timeout.as_secs());
+        }
+    };
+
+    // Shell bitti; pipe'ı hâlâ tutan arka plan işi için EOF beklenmez
+    let readers = [stdout_task.abort_handle(), stderr_task.abort_handle()];
+    let _ = tokio::time::timeout(DRAIN_GRACE, async { tokio::join!(stdout_task, stderr_task) }).await;
+    readers.iter().for_each(|r| r.abort());
+
+    let stdout = String::from_utf8_lossy(&stdout_buf.lock().unwrap()).trim().to_string();
+    let stderr = String::from_utf8_lossy(&stderr_buf.lock().unwrap()).trim().to_string();
+    let code = status.code().unwrap_or(-1);
+
+    if status.success() {
+        match (stdout.is_empty(), stderr.is_empty()) {
+            (true, true) => "None".to_string(),
+            (false, true) => stdout,
+            _ => format!("{}\n[Stderr]: {}", stdout, stderr).trim().to_string(),
+        }
+    } else {
+        let err_msg = format!("Shell Error: exit code {}\n{}", code, stderr);
+        if stdout.is_empty() {
+            err_msg.trim().to_string()
+        } else {
+            format!("{}\n{}", stdout, err_msg.trim())
+        }
+    }
+}
@@ -0,0 +1,659 @@
+// Dağıtılabilir skill paketi (.oryxskill = tar.gz) ve yerel kurulum:
+//
+//   bundle.json                          format, ad, sürüm, yazar, kütüphaneler, dosya hash'leri
+//   skill.yaml                           manifest (örnekler dahil)
+//   lib/<target triple>/<kütüphane>      her hedef için derlenmiş kütüphane (wasm32-wasip1 dahil)
+//   wrapper/<modül>.py                   varsa Python shim'i
+//
+// Her dosyanın SHA-256'sı bundle.json'da, `checksum` da bu listenin hash'i; kurulumda
+// hepsi doğrulanır, listede olmayan dosya kabul edilmez.
+//
+// `oryxis skill pack|install|uninstall|list|upgrade` — ağ yok, kaynak her zaman yerel dosya/klasör.
+// Kurulanlar memory/installed_skills.json'a yazılır (uninstall hangi dosyaları sileceğini bilsin).
+
+use semver::{Version, VersionReq};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::errors::OryxisError;
+use crate::skill_index;
+use crate::skill_manifest::{self, SkillManifest};
+use crate::skill_scaffold;
+
+const FORMAT: u32 = 1;
+const EXTENSION: &str = "oryxskill";
+const WASM_TARGET: &str = "wasm32-wasip1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub format: u32,
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    /// target triple → bundle içindeki yol
+    #[serde(default)]
+    pub libraries: BTreeMap<String, String>,
+    #[serde(default)]
+    pub wrapper: Option<String>,
+    /// bundle içindeki yol → sha256
+    pub files: BTreeMap<String, String>,
+    pub checksum: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstalledSkill {
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    pub checksum: String,
+    /// ORYXIS/ köküne göre kurulan dosyalar
+    pub files: Vec<String>,
+    pub source: String,
+}
+
+fn bundle_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::BundleError(msg.to_string())
+}
+
+fn io_err(path: &Path, e: impl std::fmt::Display) -> OryxisError {
+    OryxisError::FileOperationError(format!("{}: {}", path.display(), e))
+}
+
+fn root() -> PathBuf {
+    std::env::current_dir().unwrap_or_default()
+}
+
+/// "2.0" → 2.0.0; semver'e uymayan kısa sürümler tamamlanır.
+pub fn parse_version(version: &str) -> Result<Version, OryxisError> {
+    let v = version.trim().trim_start_matches('v');
+    if let Ok(parsed) = Version::parse(v) {
+        return Ok(parsed);
+    }
+    let mut parts: Vec<&str> = v.split('.').collect();
+    while parts.len() < 3 {
+        parts.push("0");
+    }
+    Version::parse(&parts.join(".")).map_err(|e| bundle_err(format!("invalid version `{}`: {}", version, e)))
+}
+
+pub fn parse_requirement(requirement: &str) -> Result<VersionReq, OryxisError> {
+    VersionReq::parse(requirement).map_err(|e| bundle_err(format!("invalid version requirement `{}`: {}", requirement, e)))
+}
+
+/// Bu derlemenin target triple'ı (bundle içindeki lib/<triple> klasörü)
+pub fn host_triple() -> String {
+    let arch = std::env::consts::ARCH;
+    match std::env::consts::OS {
+        "windows" => format!("{}-pc-windows-{}", arch, if cfg!(target_env = "gnu") { "gnu" } else { "msvc" }),
+        "macos" => format!("{}-apple-darwin", arch),
+        "linux" => format!("{}-unknown-linux-{}", arch, if cfg!(target_env = "musl") { "musl" } else { "gnu" }),
+        os => format!("{}-unknown-{}", arch, os),
+    }
+}
+
+/// cargo'nun triple için ürettiği kütüphane adı
+fn library_name(name: &str, triple: &str) -> String {
+    if triple == WASM_TARGET {
+        format!("{}.wasm", name)
+    } else if triple.contains("windows") {
+        format!("{}.dll", name)
+    } else if triple.contains("apple") {
+        format!("lib{}.dylib", name)
+    } else {
+        format!("lib{}.so", name)
+    }
+}
+
+fn sha256_file(path: &Path) -> Result<String, OryxisError> {
+    let bytes = std::fs::read(path).map_err(|e| io_err(path, e))?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+fn checksum(files: &BTreeMap<String, String>) -> String {
+    let listing: String = files.iter().map(|(path, hash)| format!("{}  {}\n", hash, path)).collect();
+    format!("{:x}", Sha256::digest(listing.as_bytes()))
+}
+
+fn installed_path() -> PathBuf {
+    root().join("memory/installed_skills.json")
+}
+
+pub fn load_installed() -> BTreeMap<String, InstalledSkill> {
+    std::fs::read_to_string(installed_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_installed(installed: &BTreeMap<String, InstalledSkill>) -> Result<(), OryxisError> {
+    let json = serde_json::to_string_pretty(installed).map_err(|e| OryxisError::JsonParseError(e.to_string()))?;
+    std::fs::write(installed_path(), json + "\n").map_err(|e| io_err(&installed_path(), e))
+}
+
+/// skills/ altındaki manifest'ler, ada göre
+fn manifests() -> Result<BTreeMap<String, SkillManifest>, OryxisError> {
+    Ok(skill_manifest::load_all(&skill_manifest::skills_dir())?
+        .into_iter()
+        .filter_map(|(_, m)| m.ok())
+        .map(|m| (m.name.clone(), m))
+        .collect())
+}
+
+// ---------------------------------------------------------------- pack
+
+/// Paketlenecek kütüphaneler: kurulu olan (host) + crate'in target/<triple>/release çıktıları
+fn collect_libraries(manifest: &SkillManifest) -> Result<BTreeMap<String, PathBuf>, OryxisError> {
+    let entrypoint = manifest
+        .entrypoint
+        .as_deref()
+        .ok_or_else(|| bundle_err(format!("{} has no entrypoint", manifest.name)))?;
+    let wasm = entrypoint.ends_with(".wasm");
+    let ext = Path::new(entrypoint).extension().and_then(|e| e.to_str()).unwrap_or_default();
+    if !wasm && !["dll", "so", "dylib"].contains(&ext) {
+        return Err(bundle_err(format!("only native and wasm skills can be bundled (entrypoint `{}`)", entrypoint)));
+    }
+
+    let mut libraries = BTreeMap::new();
+    if let Some(path) = skill_manifest::resolve_library(entrypoint) {
+        libraries.insert(if wasm { WASM_TARGET.to_string() } else { host_triple() }, path);
+    }
+
+    let target = skill_scaffold::crates_dir().join(&manifest.name).join("target");
+    if let Ok(entries) = std::fs::read_dir(&target) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let triple = entry.file_name().to_string_lossy().to_string();
+            if (triple == WASM_TARGET) != wasm || libraries.contains_key(&triple) {
+                continue;
+            }
+            let built = entry.path().join("release").join(library_name(&manifest.name, &triple));
+            if built.is_file() {
+                libraries.insert(triple, built);
+            }
+        }
+    }
+
+    if libraries.is_empty() {
+        return Err(bundle_err(format!("no compiled library found for {}, run `oryxis skill build {}`", manifest.name, manifest.name)));
+    }
+    Ok(libraries)
+}
+
+fn append_file(builder: &mut tar::Builder<impl std::io::Write>, from: &Path, to: &str) -> Result<(), OryxisError> {
+    builder.append_path_with_name(from, to).map_err(|e| io_err(from, e))
+}
+
+/// `oryxis skill pack <name> [out_dir]`
+pub fn pack(name: &str, out_dir: Option<&str>) -> Result<(), OryxisError> {
+    let manifest_path = skill_manifest::skills_dir().join(format!("{}.yaml", name));
+    let manifest = skill_manifest::load(&manifest_path).map_err(|_| OryxisError::SkillNotFound(name.to_string()))?;
+    parse_version(&manifest.version)?;
+
+    // bundle içi yol → diskteki dosya
+    let mut contents: BTreeMap<String, PathBuf> = BTreeMap::new();
+    contents.insert("skill.yaml".to_string(), manifest_path.clone());
+
+    let mut libraries = BTreeMap::new();
+    for (triple, path) in collect_libraries(&manifest)? {
+        let inner = format!("lib/{}/{}", triple, path.file_name().unwrap_or_default().to_string_lossy());
+        libraries.insert(triple, inner.clone());
+        contents.insert(inner, path);
+    }
+
+    let wrapper = match manifest.wrapper.as_deref().map(|w| root().join(w)).filter(|w| w.is_file()) {
+        Some(path) => {
+            let inner = format!("wrapper/{}", path.file_name().unwrap_or_default().to_string_lossy());
+            contents.insert(inner.clone(), path);
+            Some(inner)
+        }
+        None => None,
+    };
+
+    let mut files = BTreeMap::new();
+    for (inner, path) in &contents {
+        files.insert(inner.clone(), sha256_file(path)?);
+    }
+    let bundle = Bundle {
+        format: FORMAT,
+        name: manifest.name.clone(),
+        version: manifest.version.clone(),
+        author: manifest.author.clone(),
+        libraries,
+        wrapper,
+        checksum: checksum(&files),
+        files,
+    };
+
+    let out_dir = out_dir.map(PathBuf::from).unwrap_or_else(root);
+    std::fs::create_dir_all(&out_dir).map_err(|e| io_err(&out_dir, e))?;
+    let out = out_dir.join(format!("{}-{}.{}", bundle.name, bundle.version, EXTENSION));
+    let file = std::fs::File::create(&out).map_err(|e| io_err(&out, e))?;
+    let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(file, flate2::Compression::default()));
+
+    let json = serde_json::to_vec_pretty(&bundle).map_err(|e| OryxisError::JsonParseError(e.to_string()))?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(json.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder.append_data(&mut header, "bundle.json", json.as_slice()).map_err(|e| io_err(&out, e))?;
+    for (inner, path) in &contents {
+        append_file(&mut builder, path, inner)?;
+    }
+    builder
+        .into_inner()
+        .and_then(|gz| gz.finish())
+        .map_err(|e| io_err(&out, e))?;
+
+    println!("packed {} v{} → {}", bundle.name, bundle.version, out.display());
+    for triple in bundle.libraries.keys() {
+        println!("  {}", triple);
+    }
+    println!("  checksum {}", bundle.checksum);
+    Ok(())
+}
+
+// ---------------------------------------------------------------- açma / doğrulama
+
+/// Açılmış bundle; geçici klasör drop'ta silinir.
+struct Unpacked {
+    bundle: Bundle,
+    dir: PathBuf,
+    temporary: bool,
+    source: PathBuf,
+}
+
+impl Drop for Unpacked {
+    fn drop(&mut self) {
+        if self.temporary {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+}
+
+fn is_bundle_file(path: &Path) -> bool {
+    path.is_file() && path.extension().and_then(|e| e.to_str()) == Some(EXTENSION)
+}
+
+/// .oryxskill dosyası veya açılmış bundle klasörü
+fn open(source: &Path) -> Result<Unpacked, OryxisError> {
+    let (dir, temporary) = if source.is_dir() {
+        (source.to_path_buf(), false)
+    } else {
+        let n = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or_default();
+        let dir = root().join(".oryxis/bundles").join(format!("{}-{}", std::process::id(), n));
+        std::fs::create_dir_all(&dir).map_err(|e| io_err(&dir, e))?;
+        let file = std::fs::File::open(source).map_err(|e| io_err(source, e))?;
+        // unpack `..` ve mutlak yolları reddeder
+        let result = tar::Archive::new(flate2::read::GzDecoder::new(file)).unpack(&dir);
+        if let Err(e) = result {
+            let _ = std::fs::remove_dir_all(&dir);
+            return Err(bundle_err(format!("{}: not a valid .{} archive: {}", source.display(), EXTENSION, e)));
+        }
+        (dir, true)
+    };
+
+    let meta = dir.join("bundle.json");
+    let raw = std::fs::read_to_string(&meta).map_err(|_| bundle_err(format!("{}: no bundle.json", source.display())));
+    let unpacked_bundle = raw.and_then(|raw| serde_json::from_str::<Bundle>(&raw).map_err(|e| bundle_err(format!("{}: {}", meta.display(), e))));
+    let bundle = match unpacked_bundle {
+        Ok(b) => b,
+        Err(e) => {
+            if temporary {
+                let _ = std::fs::remove_dir_all(&dir);
+            }
+            return Err(e);
+        }
+    };
+    let unpacked = Unpacked { bundle, dir, temporary, source: source.to_path_buf() };
+    verify(&unpacked)?;
+    Ok(unpacked)
+}
+
+/// Bundle'daki dosyalar. Link takip edilmez: `x -> .` ya da `x -> /` sonsuz döngüye
+/// veya tüm diske yol açmasın; symlink ve özel dosyalar hata.
+fn list_files(dir: &Path, base: &Path, out: &mut Vec<String>) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("{}: {}", dir.display(), e))?;
+        let path = entry.path();
+        let rel = path.strip_prefix(base).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+        let file_type = entry.file_type().map_err(|e| format!("{}: {}", path.display(), e))?;
+        if file_type.is_dir() {
+            list_files(&path, base, out)?;
+        } else if file_type.is_file() {
+            out.push(rel);
+        } else if file_type.is_symlink() {
+            return Err(format!("`{}` is a symlink", rel));
+        } else {
+            return Err(format!("`{}` is not a regular file", rel));
+        }
+    }
+    Ok(())
+}
+
+fn verify(unpacked: &Unpacked) -> Result<(), OryxisError> {
+    let bundle = &unpacked.bundle;
+    if bundle.format != FORMAT {
+        return Err(bundle_err(format!("bundle format {} is not supported (expected {})", bundle.format, FORMAT)));
+    }
+    if checksum(&bundle.files) != bundle.checksum {
+        return Err(bundle_err(format!("{}: checksum mismatch", unpacked.source.display())));
+    }
+
+    let mut inner_paths = bundle.files.keys().chain(bundle.libraries.values()).chain(bundle.wrapper.iter());
+    if let Some(bad) = inner_paths.find(|p| !is_inner_path(p)) {
+        return Err(bundle_err(format!("{}: invalid path `{}`", unpacked.source.display(), bad)));
+    }
+    if let Some(missing) = bundle.libraries.values().chain(bundle.wrapper.iter()).find(|p| !bundle.files.contains_key(*p)) {
+        return Err(bundle_err(format!("{}: `{}` is not in the file list", unpacked.source.display(), missing)));
+    }
+
+    let mut present = Vec::new();
+    list_files(&unpacked.dir, &unpacked.dir, &mut present)
+        .map_err(|e| bundle_err(format!("{}: {}", unpacked.source.display(), e)))?;
+    for file in present.iter().filter(|f| f.as_str() != "bundle.json") {
+        if !bundle.files.contains_key(file) {
+            return Err(bundle_err(format!("{}: unexpected file `{}`", unpacked.source.display(), file)));
+        }
+    }
+    for (file, hash) in &bundle.files {
+        let path = unpacked.dir.join(file);
+        if !path.is_file() {
+            return Err(bundle_err(format!("{}: `{}` is missing", unpacked.source.display(), file)));
+        }
+        if &sha256_file(&path)? != hash {
+            return Err(bundle_err(format!("{}: `{}` does not match its checksum", unpacked.source.display(), file)));
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------- install / upgrade
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Install,
+    Upgrade,
+    Force,
+}
+
+fn check_dependencies(manifest: &SkillManifest, installed: &BTreeMap<String, SkillManifest>) -> Result<(), OryxisError> {
+    let mut problems = Vec::new();
+    for (skill, requirement) in &manifest.dependencies {
+        let req = parse_requirement(requirement)?;
+        match installed.get(skill) {
+            None => problems.push(format!("{} {} is not installed", skill, requirement)),
+            Some(dep) => match parse_version(&dep.version) {
+                Ok(v) if req.matches(&v) => {}
+                _ => problems.push(format!("{} {} is required, v{} is installed", skill, requirement, dep.version)),
+            },
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(bundle_err(format!("{} needs: {}", manifest.name, problems.join("; "))))
+    }
+}
+
+/// Skill / modül adı: ASCII harf ya da _ ile başlar, harf, rakam, _ devam eder (yol ayırıcı, `..` yok)
+fn is_safe_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_') && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Kütüphane dosya adı: tek bileşen, gizli dosya değil (libcmdlib.so, cmdlib.dll, my_skill.wasm)
+fn is_safe_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// bundle.json'daki yol: göreli, sadece normal bileşenler
+fn is_inner_path(path: &str) -> bool {
+    let path = Path::new(path);
+    !path.as_os_str().is_empty() && path.components().all(|c| matches!(c, std::path::Component::Normal(_)))
+}
+
+/// Kök içinde kalan hedef; klasörü oluşturur, gerçek yolu (symlink'ler çözülmüş) kontrol eder
+fn contained(rel: &str) -> Result<PathBuf, OryxisError> {
+    let root = root().canonicalize().map_err(|e| io_err(&root(), e))?;
+    let to = root.join(rel);
+    let parent = to.parent().ok_or_else(|| bundle_err(format!("refusing to write `{}`", rel)))?;
+    std::fs::create_dir_all(parent).map_err(|e| io_err(parent, e))?;
+    let real_parent = parent.canonicalize().map_err(|e| io_err(parent, e))?;
+    if !real_parent.starts_with(&root) {
+        return Err(bundle_err(format!("refusing to write `{}` outside the project", rel)));
+    }
+    if to.symlink_metadata().is_ok_and(|m| m.file_type().is_symlink()) {
+        return Err(bundle_err(format!("refusing to write `{}` through a symlink", rel)));
+    }
+    Ok(real_parent.join(to.file_name().unwrap_or_default()))
+}
+
+fn copy_into(from: &Path, rel: &str) -> Result<(), OryxisError> {
+    let to = contained(rel)?;
+    std::fs::copy(from, &to).map(|_| ()).map_err(|e| io_err(&to, e))
+}
+
+/// Kurulum hedefleri bundle'dan (güvenilmez) gelir; sadece şu yollar kabul edilir:
+/// skills/<ad>.yaml, skills/dll/<kütüphane>, skills/lib/<modül>.py
+fn install_targets(unpacked: &Unpacked, manifest: &SkillManifest, entrypoint: &str, library: &str) -> Result<Vec<(PathBuf, String)>, OryxisError> {
+    let bundle = &unpacked.bundle;
+    if !is_safe_name(&bundle.name) {
+        return Err(bundle_err(format!("invalid skill name `{}`", bundle.name)));
+    }
+    if Path::new(entrypoint).parent() != Some(Path::new("skills/dll")) {
+        return Err(bundle_err(format!("{}: entrypoint must be in skills/dll/, not `{}`", bundle.name, entrypoint)));
+    }
+    let lib_name = Path::new(library).file_name().unwrap_or_default().to_string_lossy().to_string();
+    if !is_safe_file_name(&lib_name) {
+        return Err(bundle_err(format!("{}: invalid library name `{}`", bundle.name, lib_name)));
+    }
+
+    let mut files = vec![
+        (unpacked.dir.join("skill.yaml"), format!("skills/{}.yaml", bundle.name)),
+        (unpacked.dir.join(library), format!("skills/dll/{}", lib_name)),
+    ];
+    if let (Some(inner), Some(target)) = (&bundle.wrapper, &manifest.wrapper) {
+        let module = target.strip_prefix("skills/lib/").and_then(|m| m.strip_suffix(".py"));
+        if !module.is_some_and(is_safe_name) {
+            return Err(bundle_err(format!("{}: wrapper must be skills/lib/<module>.py, not `{}`", bundle.name, target)));
+        }
+        files.push((unpacked.dir.join(inner), target.clone()));
+    }
+    Ok(files)
+}
+
+fn install_unpacked(unpacked: &Unpacked, mode: Mode) -> Result<(), OryxisError> {
+    let bundle = &unpacked.bundle;
+    let manifest = skill_manifest::load(&unpacked.dir.join("skill.yaml"))?;
+    if manifest.name != bundle.name || manifest.version != bundle.version {
+        return Err(bundle_err(format!(
+            "bundle.json says {} v{}, skill.yaml says {} v{}", bundle.name, bundle.version, manifest.name, manifest.version
+        )));
+    }
+
+    let all = manifests()?;
+    let new_version = parse_version(&bundle.version)?;
+    match (all.get(&bundle.name), mode) {
+        (Some(current), Mode::Install) => {
+            return Err(bundle_err(format!(
+                "{} v{} is already installed, use `oryxis skill upgrade`", current.name, current.version
+            )));
+        }
+        (Some(current), Mode::Upgrade) if parse_version(&current.version)? >= new_version => {
+            return Err(bundle_err(format!("{} v{} is already up to date (bundle is v{})", current.name, current.version, bundle.version)));
+        }
+        (None, Mode::Upgrade) => return Err(bundle_err(format!("{} is not installed", bundle.name))),
+        _ => {}
+    }
+    check_dependencies(&manifest, &all)?;
+
+    let entrypoint = manifest.entrypoint.clone().ok_or_else(|| bundle_err(format!("{} has no entrypoint", manifest.name)))?;
+    let triple = if entrypoint.ends_with(".wasm") { WASM_TARGET.to_string() } else { host_triple() };
+    let library = bundle.libraries.get(&triple).ok_or_else(|| {
+        bundle_err(format!(
+            "{} has no build for {} (has: {})",
+            bundle.name,
+            triple,
+            bundle.libraries.keys().cloned().collect::<Vec<_>>().join(", ")
+        ))
+    })?;
+
+    // Kurulacak dosyalar: kütüphane skills/dll/'e, manifest skills/'e, wrapper skills/lib/'e
+    let files = install_targets(unpacked, &manifest, &entrypoint, library)?;
+    // Biri bile kök dışına çıkıyorsa hiçbiri kopyalanmaz
+    for (_, to) in &files {
+        contained(to)?;
+    }
+
+    let mut installed = load_installed();
+    // Yükseltmede eski sürümün artık kullanılmayan dosyaları silinir
+    if let Some(previous) = installed.get(&bundle.name) {
+        for old in previous.files.iter().filter(|old| !files.iter().any(|(_, new)| new == *old)) {
+            if let Ok(path) = contained(old) {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+    for (from, to) in &files {
+        copy_into(from, to)?;
+    }
+
+    installed.insert(bundle.name.clone(), InstalledSkill {
+        version: bundle.version.clone(),
+        author: bundle.author.clone(),
+        checksum: bundle.checksum.clone(),
+        files: files.into_iter().map(|(_, to)| to).collect(),
+        source: unpacked.source.display().to_string(),
+    });
+    save_installed(&installed)?;
+    skill_index::rebuild()?;
+
+    let action = match (all.get(&bundle.name), mode) {
+        (Some(current), _) => format!("upgraded {} v{} → v{}", bundle.name, current.version, bundle.version),
+        (None, _) => format!("installed {} v{}", bundle.name, bundle.version),
+    };
+    println!("{} ({})", action, triple);
+    if !manifest.permissions.is_empty() {
+        println!("  requests: {}", manifest.permissions.join(", "));
+        println!("  review with `oryxis skills permissions {}`, restrict with `oryxis skills revoke`", bundle.name);
+    }
+    Ok(())
+}
+
+/// `oryxis skill install <file|dir> [--force]`
+pub fn install(source: &str, force: bool) -> Result<(), OryxisError> {
+    let unpacked = open(Path::new(source))?;
+    install_unpacked(&unpacked, if force { Mode::Force } else { Mode::Install })
+}
+
+/// `oryxis skill upgrade <file|dir>` — klasörde bundle.json yoksa içindeki tüm
+/// .oryxskill'ler taranır, kurulu olup daha yeni sürümü olanlar yükseltilir.
+pub fn upgrade(source: &str) -> Result<(), OryxisError> {
+    let source = Path::new(source);
+    if is_bundle_file(source) || source.join("bundle.json").is_file() {
+        return install_unpacked(&open(source)?, Mode::Upgrade);
+    }
+
+    let entries = std::fs::read_dir(source).map_err(|e| io_err(source, e))?;
+    let mut candidates: Vec<PathBuf> = entries.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| is_bundle_file(p)).collect();
+    candidates.sort();
+
+    // Aynı skill'in birden fazla sürümü varsa en yenisi
+    let mut newest: BTreeMap<String, (Version, Unpacked)> = BTreeMap::new();
+    for path in candidates {
+        let unpacked = match open(&path) {
+            Ok(u) => u,
+            Err(e) => {
+                eprintln!("[SKILLS] skipped {}", e);
+                continue;
+            }
+        };
+        let version = parse_version(&unpacked.bundle.version)?;
+        if newest.get(&unpacked.bundle.name).is_none_or(|(v, _)| version > *v) {
+            newest.insert(unpacked.bundle.name.clone(), (version, unpacked));
+        }
+    }
+
+    let current = manifests()?;
+    let mut upgraded = 0;
+    for (name, (version, unpacked)) in &newest {
+        let Some(installed) = current.get(name) else { continue };
+        if parse_version(&installed.version)? >= *version {
+            continue;
+        }
+        install_unpacked(unpacked, Mode::Upgrade)?;
+        upgraded += 1;
+    }
+    if upgraded == 0 {
+        println!("All installed skills are up to date.");
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------- uninstall / list
+
+/// `oryxis skill uninstall <name> [--force]`
+pub fn uninstall(name: &str, force: bool) -> Result<(), OryxisError> {
+    let all = manifests()?;
+    let manifest = all.get(name).ok_or_else(|| OryxisError::SkillNotFound(name.to_string()))?;
+
+    let dependents: Vec<&str> = all
+        .values()
+        .filter(|m| m.dependencies.contains_key(name))
+        .map(|m| m.name.as_str())
+        .collect();
+    if !dependents.is_empty() && !force {
+        return Err(bundle_err(format!("{} is required by {} (use --force)", name, dependents.join(", "))));
+    }
+
+    let mut installed = load_installed();
+    // Bundle ile kurulmadıysa manifest'in gösterdiği dosyalar
+    let files: Vec<PathBuf> = match installed.remove(name) {
+        Some(record) => record.files.iter().map(|f| root().join(f)).collect(),
+        None => {
+            let mut files = vec![skill_manifest::skills_dir().join(format!("{}.yaml", name))];
+            files.extend(manifest.entrypoint.as_deref().and_then(skill_manifest::resolve_library));
+            files.extend(manifest.wrapper.as_deref().map(|w| root().join(w)));
+            files
+        }
+    };
+    for file in &files {
+        if file.is_file() {
+            std::fs::remove_file(file).map_err(|e| io_err(file, e))?;
+        }
+    }
+    save_installed(&installed)?;
+    skill_index::rebuild()?;
+    println!("uninstalled {} v{} ({} file(s))", name, manifest.version, files.len());
+    Ok(())
+}
+
+/// `oryxis skill list`
+pub fn list() -> Result<(), OryxisError> {
+    let installed = load_installed();
+    for manifest in manifests()?.values() {
+        let available = manifest.entrypoint.as_deref().and_then(skill_manifest::resolve_library).is_some();
+        let source = if installed.contains_key(&manifest.name) { "bundle" } else { "local" };
+        let deps = if manifest.dependencies.is_empty() {
+            String::new()
+        } else {
+            let deps: Vec<String> = manifest.dependencies.iter().map(|(n, r)| format!("{} {}", n, r)).collect();
+            format!("  needs {}", deps.join(", "))
+        };
+        println!(
+            "  {} {:<12} v{:<8} {:<8} {:<6}{}",
+            if available { "✅" } else { "❌" },
+            manifest.name,
+            manifest.version,
+            manifest.author,
+            source,
+            deps
+        );
+    }
+    Ok(())
+}
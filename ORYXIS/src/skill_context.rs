@@ -0,0 +1,145 @@
+// Her kullanıcı mesajında skill araması yapıp en iyi eşleşmelerin kısa
+// ability/usage özetini LLM çağrısından önce context'e ekler.
+// Böylece model get_skill_index + get_yaml_content round-trip'lerini harcamaz.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use crate::skill_manifest;
+
+pub const DEFAULT_SKILL_CONTEXT_TOKENS: usize = 600;
+const TOP_K: usize = 3;
+
+struct SkillContext {
+    token_budget: usize,
+    // Bu session'da zaten gösterilen skill'ler tekrar eklenmez
+    injected: HashSet<String>,
+}
+
+static CONTEXT: OnceLock<Mutex<SkillContext>> = OnceLock::new();
+
+/// `token_budget` = 0 → injection kapalı.
+pub fn init(token_budget: usize) {
+    let _ = CONTEXT.set(Mutex::new(SkillContext { token_budget, injected: HashSet::new() }));
+}
+
+// Kaba tahmin: ~4 karakter = 1 token
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// İlk satır başlık; ability'ler mesajla ortak kelimesi olanlar önde olacak şekilde sıralı.
+fn summarize(manifest: &skill_manifest::SkillManifest, message: &str) -> Vec<String> {
+    // Native kütüphaneler host tarafından `oryxis.skills` altında üretilir
+    let (module, import) = if crate::plugins::describe(&manifest.name).is_some() {
+        (manifest.name.clone(), format!("from oryxis.skills import {}", manifest.name))
+    } else {
+        let module = manifest
+            .wrapper
+            .as_deref()
+            .and_then(|w| std::path::Path::new(w).file_stem())
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| manifest.name.clone());
+        (module.clone(), format!("import {}", module))
+    };
+
+    let description = manifest.description.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut lines = vec![format!("## {} — {}\n{}", manifest.name, description, import)];
+
+    let message_terms: HashSet<String> = skill_lib::search::analyze(message).into_iter().collect();
+    let mut abilities: Vec<_> = manifest.abilities.iter().collect();
+    abilities.sort_by_key(|(name, _)| {
+        let hits = skill_lib::search::analyze(name).iter().filter(|t| message_terms.contains(*t)).count();
+        std::cmp::Reverse(hits)
+    });
+
+    for (name, ability) in abilities {
+        let usage = if ability.usage.is_empty() { format!("{}.{}(...)", module, name) } else { ability.usage.clone() };
+        let desc = ability.description.split_whitespace().collect::<Vec<_>>().join(" ");
+        if desc.is_empty() {
+            lines.push(format!("- {}", usage));
+        } else {
+            lines.push(format!("- {}  — {}", usage, desc));
+        }
+    }
+    lines
+}
+
+/// Değişen skill'ler tekrar gösterilsin (skill_watch)
+pub fn forget(skills: &[String]) {
+    if let Some(context) = CONTEXT.get() {
+        let mut context = context.lock().unwrap();
+        for skill in skills {
+            context.injected.remove(skill);
+        }
+    }
+}
+
+/// LLM çağrısından önce eklenecek blok: diskte değişen skill'ler + hafızadan ilgili
+/// bilgiler + mesaja uyan skill özetleri.
+pub fn for_message(message: &str) -> Option<String> {
+    let parts: Vec<String> = [crate::skill_watch::take_notice(), crate::memory_recall::for_message(message), relevant(message)]
+        .into_iter()
+        .flatten()
+        .collect();
+    if parts.is_empty() { None } else { Some(parts.join("\n\n")) }
+}
+
+/// Mesaja uyan skill'lerin özet bloğu; bütçe dolunca kesilir.
+/// Eşleşme yoksa veya hepsi daha önce gösterildiyse None.
+fn relevant(message: &str) -> Option<String> {
+    let context = CONTEXT.get()?;
+    let mut context = context.lock().unwrap();
+    if context.token_budget == 0 {
+        return None;
+    }
+
+    let index = std::fs::read_to_string(crate::skill_index::index_path()).ok()?;
+    let skills: Vec<skill_lib::SkillIndex> = serde_json::from_str(&index).ok()?;
+
+    let header = "[Relevant skills — already loaded, use directly without get_skill_index / get_yaml_content]";
+    let mut block = header.to_string();
+    let mut used = estimate_tokens(header);
+    let mut added = Vec::new();
+
+    for (skill, _score) in skill_lib::search::rank(&skills, message, TOP_K) {
+        if context.injected.contains(&skill.name) {
+            continue;
+        }
+        // entrypoint'i olmayan skill'i önermek modeli hataya sürükler
+        if !crate::skill_index::is_available(skill.extra.get("entrypoint").and_then(|v| v.as_str())) {
+            continue;
+        }
+        let Ok(manifest) = skill_manifest::load(&std::env::current_dir().unwrap_or_default().join(&skill.file)) else {
+            continue;
+        };
+
+        let lines = summarize(&manifest, message);
+        let header_cost = estimate_tokens(&lines[0]) + 1;
+        if used + header_cost > context.token_budget {
+            continue;
+        }
+        block.push('\n');
+        block.push_str(&lines[0]);
+        used += header_cost;
+        added.push(skill.name.clone());
+
+        for line in &lines[1..] {
+            let cost = estimate_tokens(line) + 1;
+            if used + cost > context.token_budget {
+                block.push_str("\n- ... (more in the YAML)");
+                break;
+            }
+            block.push('\n');
+            block.push_str(line);
+            used += cost;
+        }
+    }
+
+    if added.is_empty() {
+        return None;
+    }
+    eprintln!("[SKILLS] injected {} (~{} tokens)", added.join(", "), used);
+    context.injected.extend(added);
+    Some(block)
+}
@@ -0,0 +1,131 @@
+// memory/skills_index.json artık elle yazılmıyor — skills/*.yaml'dan üretilir.
+// Startup'ta ve `oryxis skills reindex` ile çalışır.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::errors::OryxisError;
+use crate::skill_manifest::{self, SkillManifest};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillIndexEntry {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub file: String,
+    #[serde(default)]
+    pub abilities: Vec<String>,
+    #[serde(default)]
+    pub examples: Vec<String>,
+    #[serde(default)]
+    pub entrypoint: Option<String>,
+    /// false → entrypoint kütüphanesi diskte yok, skill çağrılamaz. Makineye göre değişir
+    /// (hangi kütüphaneler derlendi), o yüzden dosyaya yazılmaz, okunurken hesaplanır.
+    #[serde(skip)]
+    pub available: bool,
+}
+
+/// Entrypoint kütüphanesi bu makinede bulunuyor mu
+pub fn is_available(entrypoint: Option<&str>) -> bool {
+    entrypoint.and_then(skill_manifest::resolve_library).is_some()
+}
+
+pub fn index_path() -> PathBuf {
+    std::env::current_dir().unwrap_or_default().join("memory/skills_index.json")
+}
+
+impl SkillIndexEntry {
+    pub fn from_manifest(manifest: &SkillManifest, file: String) -> Self {
+        let available = is_available(manifest.entrypoint.as_deref());
+
+        SkillIndexEntry {
+            name: manifest.name.clone(),
+            description: manifest.description.split_whitespace().collect::<Vec<_>>().join(" "),
+            tags: manifest.tags.clone(),
+            file,
+            abilities: manifest.abilities.keys().cloned().collect(),
+            examples: manifest.examples.iter().map(|e| e.title.clone()).collect(),
+            entrypoint: manifest.entrypoint.clone(),
+            available,
+        }
+    }
+}
+
+/// skills/*.yaml'ı tarar, index'i yeniden yazar ve girdileri döndürür.
+/// Parse edilemeyen manifest'ler atlanır ve loglanır.
+pub fn rebuild() -> Result<Vec<SkillIndexEntry>, OryxisError> {
+    let root = std::env::current_dir().unwrap_or_default();
+    let mut entries = Vec::new();
+
+    for (path, manifest) in skill_manifest::load_all(&skill_manifest::skills_dir())? {
+        let manifest = match manifest {
+            Ok(m) => m,
+            Err(e) => {
+                eprintln!("[SKILLS] skipped {}", e);
+                continue;
+            }
+        };
+        let file = path
+            .strip_prefix(&root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        entries.push(SkillIndexEntry::from_manifest(&manifest, file));
+    }
+
+    let json = serde_json::to_string_pretty(&entries)
+        .map_err(|e| OryxisError::JsonParseError(e.to_string()))?
+        + "\n";
+    // Aynıysa dokunma: dosya repoda takipli, her açılışta mtime/diff üretmesin
+    if std::fs::read_to_string(index_path()).ok().as_deref() != Some(json.as_str()) {
+        std::fs::write(index_path(), json)
+            .map_err(|e| OryxisError::FileOperationError(format!("{}: {}", index_path().display(), e)))?;
+    }
+
+    Ok(entries)
+}
+
+pub fn load() -> Result<Vec<SkillIndexEntry>, OryxisError> {
+    let content = std::fs::read_to_string(index_path())
+        .map_err(|e| OryxisError::FileOperationError(format!("{}: {}", index_path().display(), e)))?;
+    let mut entries: Vec<SkillIndexEntry> =
+        serde_json::from_str(&content).map_err(|e| OryxisError::JsonParseError(e.to_string()))?;
+    for entry in &mut entries {
+        entry.available = is_available(entry.entrypoint.as_deref());
+    }
+    Ok(entries)
+}
+
+fn report(entries: &[SkillIndexEntry]) {
+    for entry in entries.iter().filter(|e| !e.available) {
+        match &entry.entrypoint {
+            Some(lib) => eprintln!("[SKILLS] {}: entrypoint library `{}` is missing", entry.name, lib),
+            None => eprintln!("[SKILLS] {}: no entrypoint library", entry.name),
+        }
+    }
+}
+
+/// Startup: index'i yeniler, eksik kütüphaneleri uyarır. Hata chat'i durdurmaz.
+pub fn reindex_on_startup() {
+    match rebuild() {
+        Ok(entries) => report(&entries),
+        Err(e) => eprintln!("[SKILLS] reindex failed: {}", e),
+    }
+}
+
+/// `oryxis skills reindex`
+pub fn reindex_command() -> Result<(), OryxisError> {
+    let entries = rebuild()?;
+    for entry in &entries {
+        println!(
+            "  {} {:<12} {} abilities, {} examples",
+            if entry.available { "✅" } else { "❌" },
+            entry.name,
+            entry.abilities.len(),
+            entry.examples.len()
+        );
+    }
+    report(&entries);
+    println!("{} skill(s) written to {}", entries.len(), index_path().display());
+    Ok(())
+}
@@ -0,0 +1,379 @@
+// skills/*.yaml için typed model + validator (`oryxis skills validate`)
+
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::errors::OryxisError;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub author: String,
+    /// Derlenmiş kütüphane (.dll/.so/.wasm/.pyd), proje köküne göre
+    #[serde(default)]
+    pub entrypoint: Option<String>,
+    /// Python köprüsü (skills/lib/*.py)
+    #[serde(default)]
+    pub wrapper: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub abilities: BTreeMap<String, Ability>,
+    #[serde(default)]
+    pub examples: Vec<Example>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub requirements: Vec<String>,
+    /// Gereken diğer skill'ler: `file_lib: ">=2.0"`
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+}
+
+impl SkillManifest {
+    /// Fonksiyonun ability'si: önce adıyla, yoksa `usage`'ı onu çağıran ability
+    /// (memorylib: `search_memory` → `memorylib.search(...)`)
+    pub fn ability_for(&self, function: &str) -> Option<&Ability> {
+        let call = format!(".{}(", function);
+        self.abilities
+            .get(function)
+            .or_else(|| self.abilities.values().find(|a| a.usage.replace(' ', "").contains(&call)))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Ability {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub usage: String,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+    #[serde(default)]
+    pub returns: Option<ParamType>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Parameter {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub kind: ParamType,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParamType {
+    #[default]
+    Any,
+    String,
+    Int,
+    Float,
+    Bool,
+    List,
+    Dict,
+    Path,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Example {
+    pub title: String,
+    #[serde(rename = "type", default)]
+    pub kind: ExampleKind,
+    pub code: String,
+    /// Hangi ability'yi gösteriyor; yoksa koddaki `skill.ability(` çağrılarından bulunur
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ability: Option<String>,
+    /// Sadece bu sistemlerde çalıştırılır (windows, linux, macos); boş → hepsi
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub os: Vec<String>,
+    /// `oryxis skills test` kontrolleri; yoksa sadece hatasız çalışması beklenir
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect: Option<Expect>,
+}
+
+/// Örnek çıktısı için beklentiler — hepsi sağlanmalı.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Expect {
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub contains: Vec<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub not_contains: Vec<String>,
+    /// Kırpılmış çıktının tamamı
+    #[serde(default)]
+    pub equals: Option<String>,
+    /// Regex
+    #[serde(default)]
+    pub matches: Option<String>,
+    /// Beklenen exception adı (PermissionError, SkillError, ...)
+    #[serde(default)]
+    pub raises: Option<String>,
+}
+
+/// `contains: "x"` ve `contains: [x, y]` ikisi de olur
+fn one_or_many<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(s) => vec![s],
+        OneOrMany::Many(v) => v,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExampleKind {
+    #[default]
+    Execute,
+    FastExecute,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug)]
+pub struct Issue {
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Issue {
+    fn error(message: String) -> Self { Issue { severity: Severity::Error, message } }
+    fn warning(message: String) -> Self { Issue { severity: Severity::Warning, message } }
+}
+
+pub fn skills_dir() -> PathBuf {
+    std::env::current_dir().unwrap_or_default().join("skills")
+}
+
+pub fn load(path: &Path) -> Result<SkillManifest, OryxisError> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| OryxisError::InvalidSkill(format!("{}: {}", path.display(), e)))?;
+    serde_yaml::from_str(&content)
+        .map_err(|e| OryxisError::InvalidSkill(format!("{}: {}", path.display(), e)))
+}
+
+/// Manifest dosyası ve parse sonucu (bozuk olan diğerlerini engellemesin)
+pub type LoadedManifest = (PathBuf, Result<SkillManifest, OryxisError>);
+
+/// skills/ altındaki tüm .yaml dosyalarını isim sırasıyla yükler.
+pub fn load_all(dir: &Path) -> Result<Vec<LoadedManifest>, OryxisError> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| OryxisError::InvalidSkill(format!("{}: {}", dir.display(), e)))?;
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("yaml"))
+        .collect();
+    paths.sort();
+
+    Ok(paths.into_iter().map(|p| { let m = load(&p); (p, m) }).collect())
+}
+
+pub fn native_lib_extension() -> &'static str {
+    if cfg!(windows) { "dll" } else if cfg!(target_os = "macos") { "dylib" } else { "so" }
+}
+
+/// entrypoint'i bu platformdaki dosyaya çözer. Yazıldığı gibi yoksa aynı klasörde
+/// platform adlandırmasını dener: skills/dll/cmdlib.dll → cmdlib.so / libcmdlib.so
+pub fn resolve_library(entrypoint: &str) -> Option<PathBuf> {
+    let path = std::env::current_dir().unwrap_or_default().join(entrypoint);
+    if path.is_file() {
+        return Some(path);
+    }
+    let stem = path.file_stem()?.to_str()?.to_string();
+    let dir = path.parent()?.to_path_buf();
+    let ext = native_lib_extension();
+    [format!("{}.{}", stem, ext), format!("lib{}.{}", stem, ext)]
+        .into_iter()
+        .map(|name| dir.join(name))
+        .find(|p| p.is_file())
+}
+
+/// Python kaynağını çalıştırmadan derler; sözdizimi hatası varsa mesajı döner.
+fn python_syntax_error(code: &str, filename: &str) -> Option<String> {
+    crate::executer::ensure_python_init();
+    Python::with_gil(|py| {
+        let builtins = py.import("builtins").ok()?;
+        match builtins.call_method1("compile", (code, filename, "exec")) {
+            Ok(_) => None,
+            Err(e) => Some(e.to_string()),
+        }
+    })
+}
+
+/// Bir manifest'i diskteki dosyalarla karşılaştırır.
+pub fn validate(path: &Path, manifest: &SkillManifest) -> Vec<Issue> {
+    let root = std::env::current_dir().unwrap_or_default();
+    let mut issues = Vec::new();
+
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+    if manifest.name.is_empty() {
+        issues.push(Issue::error("missing `name`".to_string()));
+    } else if manifest.name != stem {
+        issues.push(Issue::warning(format!("name `{}` does not match file name `{}.yaml`", manifest.name, stem)));
+    }
+    if manifest.description.trim().is_empty() {
+        issues.push(Issue::warning("missing `description`".to_string()));
+    }
+    if manifest.version.is_empty() {
+        issues.push(Issue::warning("missing `version`".to_string()));
+    }
+
+    // Skill ABI'sini export eden kütüphanenin fonksiyonları (varsa wrapper gerekmez)
+    let mut native_functions: Option<Vec<String>> = None;
+
+    match &manifest.entrypoint {
+        None => issues.push(Issue::error("missing `entrypoint`".to_string())),
+        Some(entry) => match resolve_library(entry) {
+            None => issues.push(Issue::error(format!("entrypoint `{}` not found", entry))),
+            Some(resolved) => {
+                let ext = resolved.extension().and_then(|e| e.to_str()).unwrap_or_default();
+                if let Some(loaded) = crate::plugins::load_entrypoint(&resolved, manifest) {
+                    match loaded {
+                        Ok(skill) => {
+                            native_functions = Some(skill.description.functions.into_iter().map(|f| f.name).collect())
+                        }
+                        Err(e) if manifest.wrapper.is_none() || ext == "wasm" => issues.push(Issue::error(e.to_string())),
+                        Err(_) => {}
+                    }
+                } else if ext != "py" {
+                    issues.push(Issue::warning(format!(
+                        "entrypoint `{}` is a .{} file, this platform loads .{}",
+                        entry, ext, native_lib_extension()
+                    )));
+                }
+            }
+        },
+    }
+
+    if let Some(functions) = &native_functions {
+        for ability in manifest.abilities.keys() {
+            if !functions.contains(ability) {
+                issues.push(Issue::error(format!("ability `{}` is not exported by the entrypoint library", ability)));
+            }
+        }
+    } else if let Some(wrapper) = &manifest.wrapper {
+        match std::fs::read_to_string(root.join(wrapper)) {
+            Err(e) => issues.push(Issue::error(format!("wrapper `{}`: {}", wrapper, e))),
+            Ok(source) => {
+                if let Some(err) = python_syntax_error(&source, wrapper) {
+                    issues.push(Issue::error(format!("wrapper `{}` does not compile: {}", wrapper, err)));
+                }
+                // `from oryxis.skills.x import *` shim'i: fonksiyonlar kütüphaneden üretilir,
+                // kütüphane eksikse hata entrypoint kontrolünde zaten raporlandı
+                if !source.contains("from oryxis.skills") {
+                    // Saf Python skill'de (entrypoint = wrapper) referans verilecek kütüphane yok
+                    let native = manifest.entrypoint.as_deref().filter(|e| *e != wrapper.as_str());
+                    if let Some(lib) = native.and_then(|e| Path::new(e).file_name()) {
+                        let lib = lib.to_string_lossy();
+                        if !source.contains(lib.as_ref()) {
+                            issues.push(Issue::warning(format!("wrapper `{}` does not reference `{}`", wrapper, lib)));
+                        }
+                    }
+                    for ability in manifest.abilities.keys() {
+                        if !source.contains(&format!("def {}(", ability)) {
+                            issues.push(Issue::error(format!("ability `{}` is not defined in wrapper `{}`", ability, wrapper)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for (skill, requirement) in &manifest.dependencies {
+        if let Err(e) = crate::skill_bundle::parse_requirement(requirement) {
+            issues.push(Issue::error(format!("dependency `{}`: {}", skill, e)));
+        }
+        if !skills_dir().join(format!("{}.yaml", skill)).is_file() {
+            issues.push(Issue::warning(format!("dependency `{}` is not installed", skill)));
+        }
+    }
+
+    for permission in &manifest.permissions {
+        if let Err(e) = crate::permissions::parse(permission) {
+            issues.push(Issue::error(e.to_string().trim_start_matches("Invalid skill manifest: ").to_string()));
+        }
+    }
+
+    if manifest.abilities.is_empty() {
+        issues.push(Issue::warning("no `abilities`".to_string()));
+    }
+    for (name, ability) in &manifest.abilities {
+        if ability.usage.is_empty() {
+            issues.push(Issue::warning(format!("ability `{}` has no `usage`", name)));
+        }
+    }
+
+    for example in &manifest.examples {
+        if let Some(err) = python_syntax_error(&example.code, &example.title) {
+            issues.push(Issue::error(format!("example `{}` does not parse: {}", example.title, err)));
+        }
+        if let Some(ability) = &example.ability
+            && !manifest.abilities.contains_key(ability)
+        {
+            issues.push(Issue::error(format!("example `{}` refers to unknown ability `{}`", example.title, ability)));
+        }
+        if let Some(Err(e)) = example.expect.as_ref().and_then(|x| x.matches.as_deref()).map(regex::Regex::new) {
+            issues.push(Issue::error(format!("example `{}`: `expect.matches` is not a valid regex: {}", example.title, e)));
+        }
+    }
+
+    issues
+}
+
+/// `oryxis skills validate` — tüm skill'leri doğrular, hata varsa Err döner.
+pub fn validate_all() -> Result<(), OryxisError> {
+    let dir = skills_dir();
+    let mut errors = 0;
+    let mut warnings = 0;
+
+    for (path, manifest) in load_all(&dir)? {
+        let rel = path.strip_prefix(std::env::current_dir().unwrap_or_default()).unwrap_or(&path);
+        let issues = match manifest {
+            Ok(m) => {
+                println!("{} ({})", m.name, rel.display());
+                validate(&path, &m)
+            }
+            Err(e) => {
+                println!("{}", rel.display());
+                vec![Issue::error(e.to_string())]
+            }
+        };
+
+        if issues.is_empty() {
+            println!("  ✅ ok");
+        }
+        for issue in &issues {
+            match issue.severity {
+                Severity::Error => { errors += 1; println!("  ❌ {}", issue.message); }
+                Severity::Warning => { warnings += 1; println!("  ⚠️  {}", issue.message); }
+            }
+        }
+    }
+
+    println!("\n{} error(s), {} warning(s)", errors, warnings);
+    if errors > 0 {
+        return Err(OryxisError::InvalidSkill(format!("{} error(s)", errors)));
+    }
+    Ok(())
+}
@@ -0,0 +1,231 @@
+// `oryxis skill new <name>` / `oryxis skill build <name>`
+//
+// new:   libraries_opensource/<name>/ altında export_skill! kullanan bir cdylib crate'i
+//        ve <name>.yaml manifest'i üretir, sonra build ile aynı adımları çalıştırır.
+// build: cargo build --release (entrypoint .wasm ise --target wasm32-wasip1), ardından
+//        kütüphane → skills/dll/, manifest → skills/<name>.yaml, shim → skills/lib/<name>.py,
+//        index yeniden yazılır ve skill doğrulanır.
+//
+// Manifest'in asıl kopyası crate içindekidir; skills/<name>.yaml her build'de üzerine yazılır.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::errors::OryxisError;
+use crate::skill_index;
+use crate::skill_manifest::{self, Severity};
+
+const WASM_TARGET: &str = "wasm32-wasip1";
+
+const CARGO_TOML: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+serde_json = "1"
+skill_abi = { path = "../skill_abi" }
+"#;
+
+const LIB_RS: &str = r#"// {name} — `oryxis skill new` ile üretildi.
+// Yeni fonksiyon: export_skill! listesine ekle, dispatch'e bağla, {name}.yaml'da
+// `abilities` altında tarif et, sonra `oryxis skill build {name}`.
+// Dosya/süreç/ağ erişimi öncesi skill_abi::grants::check_* çağır (manifest `permissions`).
+
+use serde_json::Value;
+
+skill_abi::export_skill! {
+    name: "{name}",
+    version: "0.1.0",
+    functions: [ hello(name) ],
+    dispatch: dispatch,
+}
+
+fn dispatch(function: &str, args: &[Value]) -> Result<Value, String> {
+    match function {
+        "hello" => {
+            let name = skill_abi::arg_str(args, 0, "name")?;
+            Ok(Value::String(format!("Hello, {}!", name)))
+        }
+        _ => Err(format!("unknown function `{}`", function)),
+    }
+}
+"#;
+
+const MANIFEST: &str = r#"name: {name}
+description: >
+  TODO: what this skill does, in one or two sentences. The skill search
+  matches user messages against this text, the tags and the abilities.
+
+version: "0.1.0"
+author: ""
+entrypoint: skills/dll/{name}.{ext}
+wrapper: skills/lib/{name}.py
+
+# fs.read:<paths>, fs.write:<paths>, process.spawn:<programs>
+permissions: []
+
+tags:
+  - {name}
+
+abilities:
+  hello:
+    description: Return a greeting for the given name.
+    usage: "{name}.hello(name)"
+    parameters:
+      - { name: name, type: string, description: "Who to greet" }
+    returns: string
+
+examples:
+  - title: Greet someone
+    code: |
+      from oryxis.skills import {name}
+      print({name}.hello("Oryxis"))
+    expect:
+      equals: Hello, Oryxis!
+"#;
+
+const SHIM: &str = "# `oryxis skill build` tarafından üretildi; fonksiyonlar host'tan gelir (oryxis.skills.{name}).\n\
+# Bu dosya `import {name}` kodları da çalışsın diye duruyor.\n\
+from oryxis.skills.{name} import *  # noqa: F401,F403\n";
+
+fn fill(template: &str, name: &str) -> String {
+    template.replace("{name}", name)
+}
+
+fn build_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::SkillBuildError(msg.to_string())
+}
+
+/// Skill crate'lerinin klasörü (ORYXIS/ ile kardeş)
+pub fn crates_dir() -> PathBuf {
+    let root = std::env::current_dir().unwrap_or_default();
+    root.parent().unwrap_or(&root).join("libraries_opensource")
+}
+
+/// Modül adı olarak import edilebilmeli: küçük harf, rakam, `_`
+fn check_name(name: &str) -> Result<(), OryxisError> {
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid {
+        return Err(build_err(format!("`{}` is not a valid skill name (use lower_snake_case)", name)));
+    }
+    Ok(())
+}
+
+fn write_new(path: &Path, content: &str) -> Result<(), OryxisError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", parent.display(), e)))?;
+    }
+    std::fs::write(path, content).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", path.display(), e)))
+}
+
+/// `oryxis skill new <name> [--wasm]`
+pub fn new_skill(name: &str, wasm: bool) -> Result<(), OryxisError> {
+    check_name(name)?;
+    let crate_dir = crates_dir().join(name);
+    if crate_dir.exists() {
+        return Err(build_err(format!("{} already exists", crate_dir.display())));
+    }
+    if skill_manifest::skills_dir().join(format!("{}.yaml", name)).exists() {
+        return Err(build_err(format!("skills/{}.yaml already exists", name)));
+    }
+    if !crates_dir().join("skill_abi").is_dir() {
+        return Err(build_err(format!("skill_abi crate not found in {}", crates_dir().display())));
+    }
+
+    let ext = if wasm { "wasm" } else { "dll" };
+    write_new(&crate_dir.join("Cargo.toml"), &fill(CARGO_TOML, name))?;
+    write_new(&crate_dir.join("src/lib.rs"), &fill(LIB_RS, name))?;
+    write_new(&crate_dir.join(format!("{}.yaml", name)), &fill(MANIFEST, name).replace("{ext}", ext))?;
+    println!("created {}", crate_dir.display());
+
+    build(name)
+}
+
+/// cargo'nun ürettiği dosya: target/release/libname.so, name.dll, target/wasm32-wasip1/release/name.wasm
+fn artifact(crate_dir: &Path, name: &str, wasm: bool) -> PathBuf {
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(|t| crate_dir.join(t))
+        .unwrap_or_else(|| crate_dir.join("target"));
+    if wasm {
+        return target.join(WASM_TARGET).join("release").join(format!("{}.wasm", name));
+    }
+    let prefix = if cfg!(windows) { "" } else { "lib" };
+    target.join("release").join(format!("{}{}.{}", prefix, name, skill_manifest::native_lib_extension()))
+}
+
+fn copy(from: &Path, to: &Path) -> Result<(), OryxisError> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", parent.display(), e)))?;
+    }
+    std::fs::copy(from, to)
+        .map(|_| ())
+        .map_err(|e| OryxisError::FileOperationError(format!("{} → {}: {}", from.display(), to.display(), e)))
+}
+
+/// `oryxis skill build <name>` — derler, skills/'e kurar, index'i yeniler.
+pub fn build(name: &str) -> Result<(), OryxisError> {
+    check_name(name)?;
+    let crate_dir = crates_dir().join(name);
+    let manifest_src = crate_dir.join(format!("{}.yaml", name));
+    let manifest = skill_manifest::load(&manifest_src)?;
+    if manifest.name != name {
+        return Err(build_err(format!("{} declares name `{}`", manifest_src.display(), manifest.name)));
+    }
+    let entrypoint = manifest
+        .entrypoint
+        .clone()
+        .ok_or_else(|| build_err(format!("{} has no entrypoint", manifest_src.display())))?;
+    let wasm = entrypoint.ends_with(".wasm");
+
+    let mut cargo = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    cargo.args(["build", "--release"]).current_dir(&crate_dir);
+    if wasm {
+        cargo.args(["--target", WASM_TARGET]);
+    }
+    println!("building {} ...", crate_dir.display());
+    let status = cargo.status().map_err(|e| build_err(format!("cannot run cargo: {}", e)))?;
+    if !status.success() {
+        return Err(build_err(format!("cargo build for `{}` exited with {}", name, status)));
+    }
+
+    let root = std::env::current_dir().unwrap_or_default();
+    let built = artifact(&crate_dir, name, wasm);
+    // entrypoint klasörüne cargo'nun verdiği adla: skills/dll/cmdlib.dll → skills/dll/libcmdlib.so
+    let lib_dir = root.join(&entrypoint).parent().map(Path::to_path_buf).unwrap_or_else(|| root.join("skills/dll"));
+    let installed = lib_dir.join(built.file_name().unwrap_or_default());
+    copy(&built, &installed)?;
+    println!("installed {}", installed.strip_prefix(&root).unwrap_or(&installed).display());
+
+    let manifest_dst = skill_manifest::skills_dir().join(format!("{}.yaml", name));
+    copy(&manifest_src, &manifest_dst)?;
+    println!("installed skills/{}.yaml", name);
+
+    if let Some(wrapper) = &manifest.wrapper {
+        let wrapper = root.join(wrapper);
+        if !wrapper.exists() {
+            write_new(&wrapper, &fill(SHIM, name))?;
+            println!("installed {}", wrapper.strip_prefix(&root).unwrap_or(&wrapper).display());
+        }
+    }
+
+    skill_index::rebuild()?;
+    println!("reindexed {}", skill_index::index_path().display());
+
+    let issues = skill_manifest::validate(&manifest_dst, &skill_manifest::load(&manifest_dst)?);
+    for issue in &issues {
+        match issue.severity {
+            Severity::Error => println!("  ❌ {}", issue.message),
+            Severity::Warning => println!("  ⚠️  {}", issue.message),
+        }
+    }
+    if issues.iter().any(|i| matches!(i.severity, Severity::Error)) {
+        return Err(OryxisError::InvalidSkill(format!("{} installed with errors", name)));
+    }
+    println!("✅ {} ready — `from oryxis.skills import {}`", name, name);
+    Ok(())
+}
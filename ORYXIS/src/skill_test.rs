@@ -0,0 +1,252 @@
+// `oryxis skills test [name]` — YAML `examples`'ları gerçek executer ile çalıştırır.
+//
+// Her örnek .oryxis/skill-test/<skill>-<n>/ altında, çalışma klasörü o olacak şekilde
+// çalışır ve sonra silinir; göreli yollar projeye dokunmaz (klasör "." izninin içinde).
+// Manifest'teki göreli izinler (`fs.write:workspace`) de sandbox'a göre yeniden verilir ve
+// bu klasörler sandbox'ta önceden açılır.
+// Sonuç `expect` ile karşılaştırılır, yoksa hatasız bitmesi yeterli:
+//
+//   expect:
+//     contains: "Hello"          # veya liste
+//     not_contains: ERROR
+//     equals: "'Hello, x!'"
+//     matches: "^\\d+ files$"
+//     raises: PermissionError
+//
+// Rapor ability bazındadır: örnek `ability:` ile ya da koddaki `skill.ability(` çağrısıyla eşleşir.
+
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::action_executor;
+use crate::errors::OryxisError;
+use crate::permissions;
+use crate::plugins;
+use crate::script::Language;
+use crate::skill_manifest::{self, Example, ExampleKind, Expect, SkillManifest};
+
+#[derive(Clone)]
+enum Outcome {
+    Pass,
+    Fail(String),
+    Skip(String),
+}
+
+fn current_os() -> &'static str {
+    if cfg!(windows) { "windows" } else if cfg!(target_os = "macos") { "macos" } else { "linux" }
+}
+
+fn sandbox_root() -> PathBuf {
+    std::env::current_dir().unwrap_or_default().join(".oryxis/skill-test")
+}
+
+/// Örneğin gösterdiği ability'ler
+fn abilities_of(manifest: &SkillManifest, example: &Example) -> Vec<String> {
+    if let Some(ability) = &example.ability {
+        return vec![ability.clone()];
+    }
+    let mut modules = vec![manifest.name.clone()];
+    if let Some(stem) = manifest.wrapper.as_deref().and_then(|w| Path::new(w).file_stem()) {
+        modules.push(stem.to_string_lossy().to_string());
+    }
+    let mut found: Vec<String> = manifest
+        .abilities
+        .keys()
+        .filter(|ability| {
+            modules.iter().any(|m| {
+                let re = format!(r"\b{}\.{}\s*\(", regex::escape(m), regex::escape(ability));
+                Regex::new(&re).is_ok_and(|re| re.is_match(&example.code))
+            })
+        })
+        .cloned()
+        .collect();
+    found.dedup();
+    found
+}
+
+fn short(output: &str) -> String {
+    let line = output.trim().replace('\n', " ⏎ ");
+    match line.char_indices().nth(160) {
+        Some((i, _)) => format!("{}…", &line[..i]),
+        None => line,
+    }
+}
+
+fn check(expect: Option<&Expect>, output: &str, failed: bool) -> Result<(), String> {
+    let Some(expect) = expect else {
+        return if failed { Err(short(output)) } else { Ok(()) };
+    };
+
+    match (&expect.raises, failed) {
+        (Some(name), true) if !output.contains(name.as_str()) => {
+            return Err(format!("expected {}, got: {}", name, short(output)));
+        }
+        (Some(name), false) => return Err(format!("expected {}, but it succeeded: {}", name, short(output))),
+        (None, true) => return Err(short(output)),
+        _ => {}
+    }
+
+    let trimmed = output.trim();
+    if let Some(equals) = &expect.equals
+        && trimmed != equals.trim()
+    {
+        return Err(format!("expected `{}`, got: {}", equals.trim(), short(output)));
+    }
+    for needle in &expect.contains {
+        if !output.contains(needle.as_str()) {
+            return Err(format!("expected output to contain `{}`, got: {}", needle, short(output)));
+        }
+    }
+    for needle in &expect.not_contains {
+        if output.contains(needle.as_str()) {
+            return Err(format!("output contains `{}`: {}", needle, short(output)));
+        }
+    }
+    if let Some(pattern) = &expect.matches {
+        let re = Regex::new(pattern).map_err(|e| format!("invalid `matches` regex: {}", e))?;
+        if !re.is_match(trimmed) {
+            return Err(format!("output does not match `{}`: {}", pattern, short(output)));
+        }
+    }
+    Ok(())
+}
+
+/// Komutlar senkron; executer async olduğu için runtime içinde bekletilir
+fn execute_python(code: &str) -> String {
+    tokio::task::block_in_place(|| {
+        tokio::runtime::Handle::current().block_on(action_executor::execute_code(Language::Python, code.to_string()))
+    })
+}
+
+/// Örneği sandbox klasöründe çalıştırır: (çıktı, hata mı)
+fn execute(manifest: &SkillManifest, example: &Example, sandbox: &Path) -> Result<(String, bool), OryxisError> {
+    let skill = manifest.name.as_str();
+    let folders = permissions::effective(manifest)
+        .into_iter()
+        .filter(|p| p.kind.starts_with("fs.") && !p.item.starts_with(['~', '*']) && Path::new(&p.item).is_relative())
+        .map(|p| sandbox.join(p.item))
+        .chain(std::iter::once(sandbox.to_path_buf()));
+    for folder in folders {
+        std::fs::create_dir_all(&folder).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", folder.display(), e)))?;
+    }
+    let root = std::env::current_dir().unwrap_or_default();
+    std::env::set_current_dir(sandbox).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", sandbox.display(), e)))?;
+    let configured = plugins::reconfigure(skill);
+
+    let (output, failed) = match (configured, &example.kind) {
+        (Err(e), _) => (e.to_string(), true),
+        (Ok(()), ExampleKind::FastExecute) => {
+            let output = action_executor::handle_fast_execute(example.code.trim());
+            let failed = output.contains("\"error\"");
+            (output, failed)
+        }
+        (Ok(()), ExampleKind::Execute) => {
+            let output = execute_python(example.code.trim());
+            let failed = action_executor::is_error_output(&output);
+            (output, failed)
+        }
+    };
+
+    std::env::set_current_dir(&root).map_err(|e| OryxisError::FileOperationError(format!("{}: {}", root.display(), e)))?;
+    plugins::reconfigure(skill)?;
+    let _ = std::fs::remove_dir_all(sandbox);
+    Ok((output, failed))
+}
+
+fn run_example(manifest: &SkillManifest, example: &Example, sandbox: &Path) -> Result<Outcome, OryxisError> {
+    if !example.os.is_empty() && !example.os.iter().any(|os| os.eq_ignore_ascii_case(current_os())) {
+        return Ok(Outcome::Skip(format!("only on {}", example.os.join(", "))));
+    }
+    let available = manifest.entrypoint.as_deref().and_then(skill_manifest::resolve_library).is_some();
+    if !available && manifest.entrypoint.is_some() {
+        return Ok(Outcome::Skip("entrypoint is missing".to_string()));
+    }
+
+    let (output, failed) = execute(manifest, example, sandbox)?;
+    Ok(match check(example.expect.as_ref(), &output, failed) {
+        Ok(()) => Outcome::Pass,
+        Err(msg) => Outcome::Fail(msg),
+    })
+}
+
+/// Bir skill'in örneklerini çalıştırır, ability bazında yazdırır. Dönen: (geçen, kalan)
+fn test_skill(manifest: &SkillManifest) -> Result<(usize, usize), OryxisError> {
+    println!("{}", manifest.name);
+
+    // ability → (örnek başlığı, sonuç)
+    let mut report: BTreeMap<String, Vec<(String, Outcome)>> = BTreeMap::new();
+    for (i, example) in manifest.examples.iter().enumerate() {
+        let sandbox = sandbox_root().join(format!("{}-{}", manifest.name, i));
+        let outcome = run_example(manifest, example, &sandbox)?;
+
+        let mut abilities = abilities_of(manifest, example);
+        if abilities.is_empty() {
+            abilities.push("(no ability)".to_string());
+        }
+        for ability in abilities {
+            report.entry(ability).or_default().push((example.title.clone(), outcome.clone()));
+        }
+    }
+
+    let (mut passed, mut failed) = (0, 0);
+    for ability in manifest.abilities.keys().filter(|a| !report.contains_key(*a)) {
+        println!("  ⚠️  {:<20} no examples", ability);
+    }
+    for (ability, results) in &report {
+        let fails = results.iter().filter(|(_, o)| matches!(o, Outcome::Fail(_))).count();
+        let passes = results.iter().filter(|(_, o)| matches!(o, Outcome::Pass)).count();
+        let skips = results.len() - fails - passes;
+        let icon = if fails > 0 { "❌" } else if passes > 0 { "✅" } else { "⏭️ " };
+        let mut summary = format!("{} passed", passes);
+        if fails > 0 {
+            summary.push_str(&format!(", {} failed", fails));
+        }
+        if skips > 0 {
+            summary.push_str(&format!(", {} skipped", skips));
+        }
+        println!("  {} {:<20} {}", icon, ability, summary);
+
+        for (title, outcome) in results {
+            match outcome {
+                Outcome::Fail(msg) => println!("       ❌ {}: {}", title, msg),
+                Outcome::Skip(why) => println!("       ⏭️  {}: {}", title, why),
+                Outcome::Pass => {}
+            }
+        }
+        if fails > 0 { failed += 1 } else if passes > 0 { passed += 1 }
+    }
+    Ok((passed, failed))
+}
+
+/// `oryxis skills test [name]`
+pub fn run(name: Option<&str>) -> Result<(), OryxisError> {
+    let manifests: Vec<SkillManifest> = skill_manifest::load_all(&skill_manifest::skills_dir())?
+        .into_iter()
+        .filter_map(|(_, m)| m.ok())
+        .filter(|m| name.is_none_or(|n| n == m.name))
+        .collect();
+    if let Some(n) = name
+        && manifests.is_empty()
+    {
+        return Err(OryxisError::SkillNotFound(n.to_string()));
+    }
+
+    plugins::load_on_startup();
+    // sys.path (proje kökü, skills/lib) sandbox'a geçmeden önce kurulsun
+    execute_python("None");
+
+    let (mut passed, mut failed) = (0, 0);
+    for manifest in &manifests {
+        let (p, f) = test_skill(manifest)?;
+        passed += p;
+        failed += f;
+    }
+    let _ = std::fs::remove_dir_all(sandbox_root());
+
+    println!("\n{} ability(ies) passed, {} failed", passed, failed);
+    if failed > 0 {
+        return Err(OryxisError::InvalidSkill(format!("{} ability(ies) failed their examples", failed)));
+    }
+    Ok(())
+}
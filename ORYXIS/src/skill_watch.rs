@@ -0,0 +1,217 @@
+// skills/ izleyicisi — YAML, kütüphane (.dll/.so/.dylib/.wasm) veya skills/lib/*.py
+// değişince Oryxis'i (ve konuşmayı) yeniden başlatmadan:
+//   - skills_index.json yeniden yazılır
+//   - etkilenen native/wasm skill'ler plugins::reload ile değiştirilir
+//     (eski kütüphane devam eden çağrı bitince kapanır)
+//   - Python tarafı bir sonraki execute'tan önce güncellenir: oryxis.skills yeniden
+//     üretilir, değişen wrapper modülleri sys.modules'dan silinir
+//   - modele bir sonraki mesajda hangi skill'lerin değiştiği söylenir (skill_context)
+//
+// Python burada doğrudan çağrılmaz: interpreter henüz başlamadıysa inittab kaydı bozulur.
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use pyo3::prelude::*;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::plugins;
+use crate::skill_manifest::{self, SkillManifest};
+
+// Derleme/kopyalama art arda birçok event üretir
+const DEBOUNCE: Duration = Duration::from_millis(400);
+
+struct Pending {
+    /// sys.modules'dan silinecek modüller (wrapper'lar)
+    py_modules: BTreeSet<String>,
+    /// oryxis.skills yeniden üretilmeli
+    py_skills: bool,
+    /// Modele bildirilecek satırlar
+    notices: Vec<String>,
+}
+
+static PENDING: Mutex<Pending> = Mutex::new(Pending { py_modules: BTreeSet::new(), py_skills: false, notices: Vec::new() });
+
+fn pending() -> std::sync::MutexGuard<'static, Pending> {
+    PENDING.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Arka plan thread'inde skills/'i izlemeye başlar. Hata chat'i durdurmaz.
+pub fn start() {
+    let dir = skill_manifest::skills_dir();
+    std::thread::spawn(move || {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(w) => w,
+            Err(e) => {
+                eprintln!("[SKILLS] watcher error: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = watcher.watch(&dir, RecursiveMode::Recursive) {
+            eprintln!("[SKILLS] cannot watch {}: {}", dir.display(), e);
+            return;
+        }
+
+        while let Ok(event) = rx.recv() {
+            let mut paths = BTreeSet::new();
+            collect(event, &mut paths);
+            while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+                collect(event, &mut paths);
+            }
+            if !paths.is_empty() {
+                apply(&paths);
+            }
+        }
+    });
+}
+
+fn collect(event: notify::Result<notify::Event>, paths: &mut BTreeSet<PathBuf>) {
+    let Ok(event) = event else { return };
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+    paths.extend(event.paths.into_iter().filter(|p| is_library(p) || has_ext(p, "yaml") || has_ext(p, "py")));
+}
+
+fn has_ext(path: &Path, ext: &str) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some(ext)
+}
+
+fn is_library(path: &Path) -> bool {
+    has_ext(path, skill_manifest::native_lib_extension()) || has_ext(path, "wasm")
+}
+
+fn stem(path: &Path) -> String {
+    path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+/// skills/dll/cmdlib.dll ↔ skills/dll/libcmdlib.so — dosya silinmiş olsa da eşleşir.
+fn entrypoint_matches(entrypoint: &str, changed: &Path) -> bool {
+    let declared = std::env::current_dir().unwrap_or_default().join(entrypoint);
+    if declared.parent() != changed.parent() {
+        return false;
+    }
+    let declared = stem(&declared);
+    let changed = stem(changed);
+    changed == declared || changed.strip_prefix("lib") == Some(declared.as_str())
+}
+
+fn wrapper_module(manifest: &SkillManifest) -> Option<String> {
+    manifest.wrapper.as_deref().map(|w| stem(Path::new(w)))
+}
+
+/// Değişen dosyalardan etkilenen skill'leri bulur ve yeniden yükler.
+fn apply(paths: &BTreeSet<PathBuf>) {
+    let manifests: Vec<SkillManifest> = skill_manifest::load_all(&skill_manifest::skills_dir())
+        .map(|all| all.into_iter().filter_map(|(_, m)| m.ok()).collect())
+        .unwrap_or_default();
+
+    let mut skills = BTreeSet::new();
+    let mut wrappers = BTreeSet::new();
+    let mut py_modules = BTreeSet::new();
+
+    for path in paths {
+        if has_ext(path, "yaml") {
+            // Manifest adı dosya adıyla aynı (validate kontrol ediyor)
+            skills.insert(stem(path));
+        } else if has_ext(path, "py") {
+            let module = stem(path);
+            for manifest in manifests.iter().filter(|m| wrapper_module(m).as_deref() == Some(module.as_str())) {
+                wrappers.insert(manifest.name.clone());
+            }
+            py_modules.insert(module);
+        } else {
+            let declared = manifests
+                .iter()
+                .filter_map(|m| Some((m.name.clone(), m.entrypoint.clone()?)))
+                .chain(plugins::builtins().iter().map(|(n, e)| (n.to_string(), e.to_string())));
+            for (name, entrypoint) in declared {
+                if entrypoint_matches(&entrypoint, path) {
+                    skills.insert(name);
+                }
+            }
+        }
+    }
+
+    if !skills.is_empty()
+        && let Err(e) = crate::skill_index::rebuild()
+    {
+        eprintln!("[SKILLS] reindex failed: {}", e);
+    }
+
+    let mut notices = Vec::new();
+    for name in &skills {
+        let exists = manifests.iter().any(|m| &m.name == name) || plugins::builtins().iter().any(|(n, _)| n == name);
+        let was_loaded = plugins::describe(name).is_some();
+        match plugins::reload(name) {
+            Ok(true) => notices.push(format!("{}: reloaded", name)),
+            Ok(false) if exists && was_loaded => notices.push(format!("{}: unloaded, entrypoint library is gone", name)),
+            Ok(false) if exists => notices.push(format!("{}: manifest updated", name)),
+            Ok(false) => notices.push(format!("{}: removed", name)),
+            Err(e) => {
+                eprintln!("[PLUGINS] {}: {}", name, e);
+                notices.push(format!("{}: failed to load ({}), unavailable until fixed", name, e));
+            }
+        }
+        // Eski `import cmdlib` shim'i de eski fonksiyonları tutuyor
+        if let Some(module) = manifests.iter().find(|m| &m.name == name).and_then(wrapper_module) {
+            py_modules.insert(module);
+        }
+    }
+    for name in wrappers.difference(&skills) {
+        notices.push(format!("{}: Python wrapper changed", name));
+    }
+
+    if notices.is_empty() && py_modules.is_empty() {
+        return;
+    }
+    eprintln!("[SKILLS] {}", if notices.is_empty() { "wrappers changed".to_string() } else { notices.join(", ") });
+
+    let changed: Vec<String> = skills.union(&wrappers).cloned().collect();
+    crate::skill_context::forget(&changed);
+
+    let mut pending = pending();
+    pending.py_skills |= !skills.is_empty();
+    pending.py_modules.extend(py_modules);
+    pending.notices.extend(notices);
+}
+
+/// Execute öncesi (GIL altında): bekleyen Python güncellemelerini uygular.
+pub fn apply_python(py: Python<'_>) -> PyResult<()> {
+    let (modules, skills) = {
+        let mut pending = pending();
+        (std::mem::take(&mut pending.py_modules), std::mem::replace(&mut pending.py_skills, false))
+    };
+    if modules.is_empty() && !skills {
+        return Ok(());
+    }
+
+    let sys_modules = py.import("sys")?.getattr("modules")?;
+    for module in modules {
+        if sys_modules.contains(&module)? {
+            sys_modules.del_item(&module)?;
+        }
+    }
+    if skills {
+        let oryxis = py.import("oryxis")?;
+        crate::py_bridge::install_skills_module(py, &oryxis)?;
+    }
+    // Yeni eklenen .py dosyaları da import edilebilsin
+    py.import("importlib")?.call_method0("invalidate_caches")?;
+    Ok(())
+}
+
+/// Son mesajdan beri değişen skill'ler; bir kez döner.
+pub fn take_notice() -> Option<String> {
+    let notices = std::mem::take(&mut pending().notices);
+    if notices.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "[Skills changed on disk and were reloaded — earlier signatures/errors may be outdated]\n- {}",
+        notices.join("\n- ")
+    ))
+}
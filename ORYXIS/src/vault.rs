@@ -0,0 +1,801 @@
+// Şifreli depolama: hafıza DB'si, konuşmalar ve sağlayıcı API anahtarları (memory/vault.json varsa).
+//
+// Veri anahtarı (rastgele 32 bayt) XChaCha20-Poly1305 ile şifrelenmiş olarak vault.json'da durur.
+// Onu açan anahtar ya passphrase'ten (Argon2id) türetilir ya da OS keyring'inde durur; keyring
+// yoksa (headless Linux) ~/.config/oryxis/keyring/<id>.key dosyasına (0600) yazılır.
+//
+// Dosyalar `<dosya>.enc` olarak saklanır. Oryxis çalışırken açık kopyaları tmpfs'teki bir
+// klasördedir ($XDG_RUNTIME_DIR ya da /dev/shm; diğer sistemlerde temp), her turdan sonra ve
+// çıkışta yeniden şifrelenir, çıkışta silinir. Çökmeden kalan kopyalar sonraki açılışta kurtarılır.
+// API anahtarları açık kopyaya hiç yazılmaz, sadece bellekte çözülür.
+//
+//   oryxis vault init [--passphrase]             oluşturur, var olan dosyaları ve .env anahtarlarını taşır
+//   oryxis vault unlock                          açar, yarım kalan oturumu kurtarır, her dosyayı doğrular
+//   oryxis vault rotate [--passphrase|--keyring] yeni veri anahtarı, her şey yeniden şifrelenir
+//   oryxis vault status
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::XChaCha20Poly1305;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use zeroize::Zeroizing;
+
+use crate::errors::OryxisError;
+use crate::permissions::Permission;
+use crate::redact;
+
+const VAULT_PATH: &str = "memory/vault.json";
+/// Şifreli saklanan dosyalar (proje köküne göre)
+const SEALED: &[&str] = &[
+    "memory/memory.db",
+    "memory/conversation.jsonl",
+    "memory/learned_memories.json",
+    "memory/memory_vectors.json",
+];
+const SECRETS_PATH: &str = "memory/secrets.json";
+const MAGIC: &[u8; 8] = b"ORYXVLT1";
+const NONCE_LEN: usize = 24;
+const KEYRING_SERVICE: &str = "oryxis-vault";
+const PID_FILE: &str = "oryxis.pid";
+/// Yarım kalmış rotate'in dosyaları
+const ROTATE_SUFFIX: &str = "rotate";
+
+type Key = Zeroizing<Vec<u8>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeySource {
+    Passphrase,
+    OsKeyring,
+    FileKeyring,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Kdf {
+    algorithm: String,
+    salt: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VaultFile {
+    version: u32,
+    id: String,
+    key_source: KeySource,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kdf: Option<Kdf>,
+    /// Veri anahtarı, açma anahtarıyla şifreli (hex)
+    wrapped_key: String,
+}
+
+/// Açık oturum
+struct Session {
+    root: PathBuf,
+    id: String,
+    key: Key,
+    work: PathBuf,
+    /// dosya → son şifrelenen içeriğin hash'i
+    sealed: Mutex<HashMap<&'static str, u64>>,
+}
+
+static SESSION: OnceLock<Session> = OnceLock::new();
+
+fn vault_err(msg: impl std::fmt::Display) -> OryxisError {
+    OryxisError::VaultError(msg.to_string())
+}
+
+fn io_err(path: &Path, e: impl std::fmt::Display) -> OryxisError {
+    OryxisError::FileOperationError(format!("{}: {}", path.display(), e))
+}
+
+fn root() -> PathBuf {
+    std::env::current_dir().unwrap_or_default()
+}
+
+pub fn is_initialized() -> bool {
+    root().join(VAULT_PATH).is_file()
+}
+
+// FNV-1a: değişmeyen dosyayı yeniden şifrelememek için
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |h, b| (h ^ *b as u64).wrapping_mul(0x100000001b3))
+}
+
+fn random_bytes(n: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; n];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn enc_path(root: &Path, rel: &str) -> PathBuf {
+    root.join(format!("{}.enc", rel))
+}
+
+fn file_name(rel: &str) -> &str {
+    rel.rsplit('/').next().unwrap_or(rel)
+}
+
+// Dosya adı AAD'de: şifreli dosyalar birbirinin yerine konamaz
+fn aad(id: &str, rel: &str) -> Vec<u8> {
+    format!("oryxis-vault:{}:{}", id, rel).into_bytes()
+}
+
+fn seal(key: &[u8], aad: &[u8], plain: &[u8]) -> Result<Vec<u8>, OryxisError> {
+    let cipher = XChaCha20Poly1305::new_from_slice(key).map_err(vault_err)?;
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&nonce, Payload { msg: plain, aad }).map_err(|_| vault_err("encryption failed"))?;
+    let mut out = MAGIC.to_vec();
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn open_sealed(key: &[u8], aad: &[u8], data: &[u8]) -> Result<Zeroizing<Vec<u8>>, OryxisError> {
+    let body = data.strip_prefix(MAGIC.as_slice()).ok_or_else(|| vault_err("not a vault file"))?;
+    if body.len() < NONCE_LEN {
+        return Err(vault_err("vault file is truncated"));
+    }
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    let cipher = XChaCha20Poly1305::new_from_slice(key).map_err(vault_err)?;
+    cipher
+        .decrypt(nonce.into(), Payload { msg: ciphertext, aad })
+        .map(Zeroizing::new)
+        .map_err(|_| vault_err("wrong key or the file was modified"))
+}
+
+/// tmp + rename: yarıda kesilen yazma eski dosyayı bozmaz
+fn write_atomic(path: &Path, data: &[u8]) -> Result<(), OryxisError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| io_err(parent, e))?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, data).map_err(|e| io_err(&tmp, e))?;
+    std::fs::rename(&tmp, path).map_err(|e| io_err(path, e))
+}
+
+fn load_vault(root: &Path) -> Result<VaultFile, OryxisError> {
+    let path = root.join(VAULT_PATH);
+    let raw = std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?;
+    serde_json::from_str(&raw).map_err(|e| vault_err(format!("{}: {}", path.display(), e)))
+}
+
+// ---- açma anahtarı: passphrase, OS keyring, dosya keyring'i ----
+
+fn derive(passphrase: &str, kdf: &Kdf) -> Result<Key, OryxisError> {
+    if kdf.algorithm != "argon2id" {
+        return Err(vault_err(format!("unknown kdf `{}`", kdf.algorithm)));
+    }
+    let salt = hex::decode(&kdf.salt).map_err(vault_err)?;
+    let params = argon2::Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32)).map_err(vault_err)?;
+    let argon = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+    let mut key = Zeroizing::new(vec![0u8; 32]);
+    argon.hash_password_into(passphrase.as_bytes(), &salt, &mut key).map_err(vault_err)?;
+    Ok(key)
+}
+
+fn new_kdf() -> Kdf {
+    let defaults = argon2::Params::default();
+    Kdf {
+        algorithm: "argon2id".to_string(),
+        salt: hex::encode(random_bytes(16)),
+        m_cost: defaults.m_cost(),
+        t_cost: defaults.t_cost(),
+        p_cost: defaults.p_cost(),
+    }
+}
+
+/// ORYXIS_VAULT_PASSPHRASE (betikler için) ya da terminalden, ekrana basmadan
+fn ask_passphrase(prompt: &str) -> Result<Zeroizing<String>, OryxisError> {
+    if let Ok(passphrase) = std::env::var("ORYXIS_VAULT_PASSPHRASE") {
+        return Ok(Zeroizing::new(passphrase));
+    }
+    rpassword::prompt_password(prompt).map(Zeroizing::new).map_err(|e| vault_err(format!("cannot read passphrase: {}", e)))
+}
+
+fn new_passphrase() -> Result<Zeroizing<String>, OryxisError> {
+    let passphrase = ask_passphrase("🔐 New vault passphrase: ")?;
+    if passphrase.chars().count() < 8 {
+        return Err(vault_err("passphrase must be at least 8 characters"));
+    }
+    if std::env::var_os("ORYXIS_VAULT_PASSPHRASE").is_none() && *ask_passphrase("🔐 Repeat passphrase: ")? != *passphrase {
+        return Err(vault_err("passphrases do not match"));
+    }
+    Ok(passphrase)
+}
+
+/// Keyring yoksa unlock anahtarlarının durduğu klasör; skill'ler buna hiç erişemez
+pub fn keyring_dir() -> PathBuf {
+    let config = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+    };
+    config.unwrap_or_else(root).join("oryxis/keyring")
+}
+
+fn keyring_file(id: &str) -> PathBuf {
+    keyring_dir().join(format!("{}.key", id))
+}
+
+#[cfg(feature = "keyring")]
+fn os_keyring(id: &str) -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYRING_SERVICE, id).map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "keyring")]
+fn os_keyring_get(id: &str) -> Result<String, String> {
+    os_keyring(id)?.get_password().map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "keyring")]
+fn os_keyring_set(id: &str, secret: &str) -> Result<(), String> {
+    os_keyring(id)?.set_password(secret).map_err(|e| e.to_string())
+}
+
+#[cfg(feature = "keyring")]
+fn os_keyring_delete(id: &str) {
+    if let Ok(entry) = os_keyring(id) {
+        let _ = entry.delete_credential();
+    }
+}
+
+#[cfg(not(feature = "keyring"))]
+fn os_keyring_get(_id: &str) -> Result<String, String> {
+    Err(format!("built without the `keyring` feature ({})", KEYRING_SERVICE))
+}
+
+#[cfg(not(feature = "keyring"))]
+fn os_keyring_set(_id: &str, _secret: &str) -> Result<(), String> {
+    Err("built without the `keyring` feature".to_string())
+}
+
+#[cfg(not(feature = "keyring"))]
+fn os_keyring_delete(_id: &str) {}
+
+fn write_keyring_file(path: &Path, secret: &str) -> Result<(), OryxisError> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+    std::fs::create_dir_all(dir).map_err(|e| io_err(dir, e))?;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        let _ = std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700));
+        options.mode(0o600);
+    }
+    use std::io::Write;
+    options
+        .open(path)
+        .and_then(|mut f| f.write_all(secret.as_bytes()))
+        .map_err(|e| io_err(path, e))
+}
+
+/// Yeni açma anahtarı; keyring istenip OS keyring'i yoksa dosyaya düşer
+fn new_unlock_key(id: &str, source: KeySource) -> Result<(Key, KeySource, Option<Kdf>), OryxisError> {
+    if source == KeySource::Passphrase {
+        let kdf = new_kdf();
+        let key = derive(&new_passphrase()?, &kdf)?;
+        return Ok((key, KeySource::Passphrase, Some(kdf)));
+    }
+    let key = Zeroizing::new(random_bytes(32));
+    let secret = Zeroizing::new(hex::encode(&*key));
+    match os_keyring_set(id, &secret) {
+        Ok(()) => Ok((key, KeySource::OsKeyring, None)),
+        Err(e) => {
+            let path = keyring_file(id);
+            println!("⚠️  no OS keyring ({}), storing the vault key in {}", e, path.display());
+            write_keyring_file(&path, &secret)?;
+            Ok((key, KeySource::FileKeyring, None))
+        }
+    }
+}
+
+fn unlock_key(vault: &VaultFile) -> Result<Key, OryxisError> {
+    let secret = match vault.key_source {
+        KeySource::Passphrase => {
+            let kdf = vault.kdf.as_ref().ok_or_else(|| vault_err("passphrase vault without kdf parameters"))?;
+            return derive(&ask_passphrase("🔐 Vault passphrase: ")?, kdf);
+        }
+        KeySource::OsKeyring => Zeroizing::new(os_keyring_get(&vault.id).map_err(|e| vault_err(format!("OS keyring: {}", e)))?),
+        KeySource::FileKeyring => {
+            let path = keyring_file(&vault.id);
+            Zeroizing::new(std::fs::read_to_string(&path).map_err(|e| io_err(&path, e))?)
+        }
+    };
+    hex::decode(secret.trim()).map(Zeroizing::new).map_err(|e| vault_err(format!("keyring entry: {}", e)))
+}
+
+fn forget_unlock_key(vault: &VaultFile) {
+    match vault.key_source {
+        KeySource::OsKeyring => os_keyring_delete(&vault.id),
+        KeySource::FileKeyring => {
+            let _ = std::fs::remove_file(keyring_file(&vault.id));
+        }
+        KeySource::Passphrase => {}
+    }
+}
+
+/// vault.json → veri anahtarı
+fn data_key(vault: &VaultFile) -> Result<Key, OryxisError> {
+    let unlock = unlock_key(vault)?;
+    let wrapped = hex::decode(&vault.wrapped_key).map_err(vault_err)?;
+    open_sealed(&unlock, &aad(&vault.id, "key"), &wrapped).map_err(|_| vault_err("cannot unlock the vault: wrong passphrase or key"))
+}
+
+// ---- açık oturum: tmpfs'teki kopyalar ----
+
+fn runtime_dir() -> PathBuf {
+    if cfg!(target_os = "linux") {
+        if let Some(dir) = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from).filter(|d| d.is_dir()) {
+            return dir;
+        }
+        if Path::new("/dev/shm").is_dir() {
+            return PathBuf::from("/dev/shm");
+        }
+    }
+    std::env::temp_dir()
+}
+
+fn work_dir(id: &str) -> PathBuf {
+    runtime_dir().join(format!("oryxis-vault-{}", id))
+}
+
+fn create_private_dir(dir: &Path) -> Result<(), OryxisError> {
+    std::fs::create_dir_all(dir).map_err(|e| io_err(dir, e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700)).map_err(|e| io_err(dir, e))?;
+    }
+    Ok(())
+}
+
+/// Kopyaları açan başka bir Oryxis hâlâ çalışıyor mu (Linux'ta /proc'tan; başka yerde bilinmiyor → hayır)
+fn running_owner(work: &Path) -> Option<u32> {
+    let pid: u32 = std::fs::read_to_string(work.join(PID_FILE)).ok()?.trim().parse().ok()?;
+    if pid == std::process::id() || !cfg!(target_os = "linux") {
+        return None;
+    }
+    // "pid (komut) durum ..."; öldürülüp toplanmamış (Z) süreç çalışmıyor sayılır
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    let state = stat.rsplit_once(") ")?.1.chars().next()?;
+    (state != 'Z').then_some(pid)
+}
+
+/// Kopyayı şifreleyip `.enc`'e yazar; içerik değişmediyse dokunmaz
+fn seal_file(root: &Path, id: &str, key: &[u8], work: &Path, rel: &str, last: Option<u64>) -> Result<Option<u64>, OryxisError> {
+    let copy = work.join(file_name(rel));
+    let Ok(plain) = std::fs::read(&copy).map(Zeroizing::new) else { return Ok(None) };
+    let hash = fnv1a(&plain);
+    if last != Some(hash) {
+        write_atomic(&enc_path(root, rel), &seal(key, &aad(id, rel), &plain)?)?;
+    }
+    Ok(Some(hash))
+}
+
+fn read_sealed(root: &Path, id: &str, key: &[u8], rel: &str) -> Result<Option<Zeroizing<Vec<u8>>>, OryxisError> {
+    let path = enc_path(root, rel);
+    match std::fs::read(&path) {
+        Ok(data) => open_sealed(key, &aad(id, rel), &data).map(Some).map_err(|e| vault_err(format!("{}: {}", path.display(), e))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(io_err(&path, e)),
+    }
+}
+
+/// rotate'in son adımı; kesildiyse sonraki açılışta da çağrılır (resumed): vault.json.rotate
+/// yazıldıysa yeni dosyalar tamdır, taşımayı bitir; yoksa at
+fn finish_rotation(root: &Path, resumed: bool) -> Result<(), OryxisError> {
+    let marker = root.join(format!("{}.{}", VAULT_PATH, ROTATE_SUFFIX));
+    let committed = marker.is_file();
+    for rel in SEALED.iter().chain([&SECRETS_PATH]) {
+        let staged = root.join(format!("{}.enc.{}", rel, ROTATE_SUFFIX));
+        if staged.is_file() {
+            if committed {
+                std::fs::rename(&staged, enc_path(root, rel)).map_err(|e| io_err(&staged, e))?;
+            } else {
+                let _ = std::fs::remove_file(&staged);
+            }
+        }
+    }
+    if committed {
+        std::fs::rename(&marker, root.join(VAULT_PATH)).map_err(|e| io_err(&marker, e))?;
+        if resumed {
+            eprintln!("[VAULT] finished an interrupted key rotation");
+        }
+    }
+    Ok(())
+}
+
+/// Chat ve hafıza komutları başlarken: vault varsa açar, kopyaları hazırlar. Guard düşünce
+/// her şey şifrelenir ve kopyalar silinir.
+pub fn open() -> Result<Option<Guard>, OryxisError> {
+    if !is_initialized() || SESSION.get().is_some() {
+        return Ok(None);
+    }
+    let root = root();
+    finish_rotation(&root, true)?;
+    let vault = load_vault(&root)?;
+    let key = data_key(&vault)?;
+    let work = work_dir(&vault.id);
+    if let Some(pid) = running_owner(&work) {
+        return Err(vault_err(format!("the vault is already open in another Oryxis (pid {})", pid)));
+    }
+    create_private_dir(&work)?;
+
+    let mut sealed = HashMap::new();
+    for rel in SEALED {
+        // Çökmeden kalan kopya: önce onu kaydet
+        if work.join(file_name(rel)).is_file() {
+            seal_file(&root, &vault.id, &key, &work, rel, None)?;
+            eprintln!("[VAULT] recovered unsaved changes to {}", rel);
+        }
+        // Vault'tan habersiz yazılmış açık dosya: içeri al
+        let plain_path = root.join(rel);
+        if plain_path.is_file() {
+            if enc_path(&root, rel).exists() {
+                eprintln!("[VAULT] both {} and its encrypted copy exist; the plaintext file is ignored", rel);
+            } else {
+                let plain = Zeroizing::new(std::fs::read(&plain_path).map_err(|e| io_err(&plain_path, e))?);
+                write_atomic(&enc_path(&root, rel), &seal(&key, &aad(&vault.id, rel), &plain)?)?;
+                std::fs::remove_file(&plain_path).map_err(|e| io_err(&plain_path, e))?;
+                eprintln!("[VAULT] encrypted {}", rel);
+            }
+        }
+        if let Some(plain) = read_sealed(&root, &vault.id, &key, rel)? {
+            let copy = work.join(file_name(rel));
+            write_atomic(&copy, &plain)?;
+            sealed.insert(*rel, fnv1a(&plain));
+        }
+    }
+    std::fs::write(work.join(PID_FILE), std::process::id().to_string()).map_err(|e| io_err(&work, e))?;
+
+    // memorylib veritabanını ORYXIS_MEMORY_DB'den okur
+    if std::env::var_os("ORYXIS_MEMORY_DB").is_some() {
+        eprintln!("[VAULT] ORYXIS_MEMORY_DB is set, that database is not encrypted");
+    } else {
+        // SAFETY: startup'ta, skill'ler yüklenmeden ve env'i okuyan iş başlamadan
+        unsafe { std::env::set_var("ORYXIS_MEMORY_DB", work.join(file_name(SEALED[0]))) };
+    }
+
+    let _ = SESSION.set(Session { root, id: vault.id, key, work, sealed: Mutex::new(sealed) });
+    Ok(Some(Guard))
+}
+
+/// Açık oturumun sonu: düşünce şifreler ve kopyaları siler
+pub struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let Some(session) = SESSION.get() else { return };
+        if let Err(e) = checkpoint_session(session) {
+            eprintln!("[VAULT] {} — the open copies are kept in {}", e, session.work.display());
+            return;
+        }
+        let _ = std::fs::remove_dir_all(&session.work);
+    }
+}
+
+fn checkpoint_session(session: &Session) -> Result<(), OryxisError> {
+    let mut sealed = session.sealed.lock().unwrap_or_else(|e| e.into_inner());
+    for rel in SEALED {
+        let last = sealed.get(rel).copied();
+        if let Some(hash) = seal_file(&session.root, &session.id, &session.key, &session.work, rel, last)? {
+            sealed.insert(rel, hash);
+        }
+    }
+    Ok(())
+}
+
+/// Her turdan sonra: değişen kopyaları şifreler (elektrik kesilirse kayıp bir turla sınırlı)
+pub fn checkpoint() {
+    if let Some(session) = SESSION.get()
+        && let Err(e) = checkpoint_session(session)
+    {
+        eprintln!("[VAULT] {}", e);
+    }
+}
+
+/// Şifreli dosyanın o an kullanılacak yolu: vault açıksa kopyası, değilse kendisi
+pub fn path(rel: &str) -> PathBuf {
+    match SESSION.get() {
+        Some(session) if SEALED.contains(&rel) => session.work.join(file_name(rel)),
+        _ => root().join(rel),
+    }
+}
+
+/// Kopyaların klasörü proje dışında: `memory`'e izni olan skill oraya da erişir
+pub fn mirror_grants(permissions: &[Permission]) -> Vec<String> {
+    let Some(session) = SESSION.get() else { return Vec::new() };
+    let mut kinds: Vec<&str> = permissions
+        .iter()
+        .filter(|p| p.kind.starts_with("fs.") && p.item != "*")
+        .filter(|p| SEALED.iter().any(|rel| session.root.join(rel).starts_with(p.host_path())))
+        .map(|p| p.kind.as_str())
+        .collect();
+    kinds.dedup();
+    kinds.into_iter().map(|kind| format!("{}:{}", kind, session.work.display())).collect()
+}
+
+fn load_secrets(root: &Path, id: &str, key: &[u8]) -> Result<BTreeMap<String, String>, OryxisError> {
+    match read_sealed(root, id, key, SECRETS_PATH)? {
+        Some(plain) => serde_json::from_slice(&plain).map_err(|e| vault_err(format!("{}: {}", SECRETS_PATH, e))),
+        None => Ok(BTreeMap::new()),
+    }
+}
+
+/// Vault'taki sağlayıcı anahtarı (API_KEY, ...); vault kapalıysa ya da yoksa None
+pub fn secret(name: &str) -> Option<String> {
+    secrets().remove(name)
+}
+
+/// Vault'taki bütün anahtarlar (redact onları çıktıda arar); vault kapalıysa boş
+pub fn secrets() -> BTreeMap<String, String> {
+    let Some(session) = SESSION.get() else { return BTreeMap::new() };
+    load_secrets(&session.root, &session.id, &session.key).unwrap_or_else(|e| {
+        eprintln!("[VAULT] {}", e);
+        BTreeMap::new()
+    })
+}
+
+// ---- .env'deki anahtarlar ----
+
+/// .env'deki anahtarları çıkarır: (anahtarlar, kalan .env). .env yoksa None.
+fn take_env_secrets() -> Option<(PathBuf, BTreeMap<String, String>, String)> {
+    let path = dotenv::dotenv().ok()?;
+    let raw = std::fs::read_to_string(&path).ok()?;
+    let mut secrets = BTreeMap::new();
+    let mut rest = String::new();
+    for line in raw.lines() {
+        let parsed = line.trim().trim_start_matches("export ").split_once('=');
+        match parsed.filter(|(name, _)| redact::is_secret_name(name.trim())) {
+            Some((name, value)) => {
+                let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+                secrets.insert(name.trim().to_string(), value.to_string());
+            }
+            None => {
+                rest.push_str(line);
+                rest.push('\n');
+            }
+        }
+    }
+    Some((path, secrets, rest))
+}
+
+fn write_secrets(id: &str, key: &[u8], secrets: &BTreeMap<String, String>, target: &Path) -> Result<(), OryxisError> {
+    let json = Zeroizing::new(serde_json::to_vec(secrets).map_err(|e| vault_err(e.to_string()))?);
+    write_atomic(target, &seal(key, &aad(id, SECRETS_PATH), &json)?)
+}
+
+/// .env'deki anahtarları vault'a ekler ve .env'den siler
+fn import_env_secrets(root: &Path, id: &str, key: &[u8]) -> Result<(), OryxisError> {
+    let Some((env_path, found, rest)) = take_env_secrets() else { return Ok(()) };
+    if found.is_empty() {
+        return Ok(());
+    }
+    let mut secrets = load_secrets(root, id, key)?;
+    let names: Vec<String> = found.keys().cloned().collect();
+    secrets.extend(found);
+    write_secrets(id, key, &secrets, &enc_path(root, SECRETS_PATH))?;
+    write_atomic(&env_path, rest.as_bytes())?;
+    println!("🔑 moved {} from {} into the vault", names.join(", "), env_path.display());
+    Ok(())
+}
+
+// ---- komutlar ----
+
+/// `oryxis vault init [--passphrase]`
+pub fn init(passphrase: bool) -> Result<(), OryxisError> {
+    let root = root();
+    if is_initialized() {
+        return Err(vault_err(format!("{} already exists (use `oryxis vault rotate`)", VAULT_PATH)));
+    }
+    let id = hex::encode(random_bytes(8));
+    let source = if passphrase { KeySource::Passphrase } else { KeySource::OsKeyring };
+    let (unlock, source, kdf) = new_unlock_key(&id, source)?;
+    let key: Key = Zeroizing::new(random_bytes(32));
+    let wrapped = hex::encode(seal(&unlock, &aad(&id, "key"), &key)?);
+
+    // Önce dosyalar, sonra vault.json: yarıda kalırsa açık dosyalar yerinde kalır
+    let mut encrypted = Vec::new();
+    for rel in SEALED {
+        let path = root.join(rel);
+        if let Ok(plain) = std::fs::read(&path).map(Zeroizing::new) {
+            write_atomic(&enc_path(&root, rel), &seal(&key, &aad(&id, rel), &plain)?)?;
+            encrypted.push((*rel, path));
+        }
+    }
+    let vault = VaultFile { version: 1, id: id.clone(), key_source: source, kdf, wrapped_key: wrapped };
+    let json = serde_json::to_string_pretty(&vault).map_err(|e| vault_err(e.to_string()))?;
+    write_atomic(&root.join(VAULT_PATH), json.as_bytes())?;
+
+    for (rel, path) in &encrypted {
+        std::fs::remove_file(path).map_err(|e| io_err(path, e))?;
+        println!("🔒 encrypted {}", rel);
+    }
+    import_env_secrets(&root, &id, &key)?;
+
+    let how = match source {
+        KeySource::Passphrase => "a passphrase (asked at startup, or ORYXIS_VAULT_PASSPHRASE)".to_string(),
+        KeySource::OsKeyring => "the OS keyring".to_string(),
+        KeySource::FileKeyring => format!("the key file {}", keyring_file(&id).display()),
+    };
+    println!("✅ vault created ({}), unlocked with {}", VAULT_PATH, how);
+    Ok(())
+}
+
+/// `oryxis vault unlock` — açılabildiğini doğrular, çökmeden kalan kopyaları kaydeder
+pub fn unlock() -> Result<(), OryxisError> {
+    if !is_initialized() {
+        return Err(vault_err("no vault here, run `oryxis vault init`"));
+    }
+    match open()? {
+        Some(guard) => drop(guard),
+        None => return Ok(()),
+    }
+    let session = SESSION.get().ok_or_else(|| vault_err("vault did not open"))?;
+    let secrets = load_secrets(&session.root, &session.id, &session.key)?;
+    for rel in SEALED {
+        if enc_path(&session.root, rel).exists() {
+            println!("  🔓 {}", rel);
+        }
+    }
+    if !secrets.is_empty() {
+        println!("  🔑 {}", secrets.keys().cloned().collect::<Vec<_>>().join(", "));
+    }
+    println!("✅ vault unlocked and verified");
+    Ok(())
+}
+
+/// `oryxis vault rotate [--passphrase|--keyring]` — yeni veri anahtarı (ve açma anahtarı)
+pub fn rotate(source: Option<KeySource>) -> Result<(), OryxisError> {
+    let root = root();
+    if !is_initialized() {
+        return Err(vault_err("no vault here, run `oryxis vault init`"));
+    }
+    finish_rotation(&root, true)?;
+    let old = load_vault(&root)?;
+    if let Some(pid) = running_owner(&work_dir(&old.id)) {
+        return Err(vault_err(format!("the vault is open in another Oryxis (pid {}), close it first", pid)));
+    }
+    if work_dir(&old.id).exists() {
+        return Err(vault_err("a previous session did not close cleanly, run `oryxis vault unlock` first"));
+    }
+    let old_key = data_key(&old)?;
+
+    // Hepsi önce çözülür: biri bozuksa hiçbir şey değişmez
+    let mut files = Vec::new();
+    for rel in SEALED {
+        if let Some(plain) = read_sealed(&root, &old.id, &old_key, rel)? {
+            files.push((*rel, plain));
+        }
+    }
+    let secrets = load_secrets(&root, &old.id, &old_key)?;
+
+    // Yeni id → yeni keyring girdisi: commit'ten önce kesilirse eski anahtar yerinde durur
+    let id = hex::encode(random_bytes(8));
+    let source = source.unwrap_or(if old.key_source == KeySource::Passphrase { KeySource::Passphrase } else { KeySource::OsKeyring });
+    let (unlock, source, kdf) = new_unlock_key(&id, source)?;
+    let key: Key = Zeroizing::new(random_bytes(32));
+
+    let staged = |rel: &str| root.join(format!("{}.enc.{}", rel, ROTATE_SUFFIX));
+    for (rel, plain) in &files {
+        write_atomic(&staged(rel), &seal(&key, &aad(&id, rel), plain)?)?;
+    }
+    if !secrets.is_empty() {
+        write_secrets(&id, &key, &secrets, &staged(SECRETS_PATH))?;
+    }
+    let vault = VaultFile { version: 1, id: id.clone(), key_source: source, kdf, wrapped_key: hex::encode(seal(&unlock, &aad(&id, "key"), &key)?) };
+    let json = serde_json::to_string_pretty(&vault).map_err(|e| vault_err(e.to_string()))?;
+    // Commit noktası: bundan sonra yarıda kalırsa finish_rotation tamamlar
+    write_atomic(&root.join(format!("{}.{}", VAULT_PATH, ROTATE_SUFFIX)), json.as_bytes())?;
+    finish_rotation(&root, false)?;
+
+    forget_unlock_key(&old);
+    import_env_secrets(&root, &id, &key)?;
+    println!("✅ vault re-encrypted with a new key ({} file(s){})", files.len(), if secrets.is_empty() { "" } else { " and the API keys" });
+    Ok(())
+}
+
+/// `oryxis vault status`
+pub fn status() -> Result<(), OryxisError> {
+    let root = root();
+    if !is_initialized() {
+        println!("No vault: memory, conversations and API keys are stored in plaintext (`oryxis vault init` to encrypt them).");
+        return Ok(());
+    }
+    let vault = load_vault(&root)?;
+    let source = match vault.key_source {
+        KeySource::Passphrase => "passphrase (argon2id)".to_string(),
+        KeySource::OsKeyring => "OS keyring".to_string(),
+        KeySource::FileKeyring => format!("key file {}", keyring_file(&vault.id).display()),
+    };
+    println!("vault {} — {}", vault.id, source);
+    for rel in SEALED.iter().chain([&SECRETS_PATH]) {
+        if let Ok(meta) = std::fs::metadata(enc_path(&root, rel)) {
+            println!("  🔒 {}.enc ({} bytes)", rel, meta.len());
+        }
+        if root.join(rel).is_file() {
+            println!("  ⚠️  {} is in plaintext, it will be encrypted on the next start", rel);
+        }
+    }
+    let work = work_dir(&vault.id);
+    match running_owner(&work) {
+        Some(pid) => println!("open in Oryxis pid {}", pid),
+        None if work.exists() => println!("⚠️  a session did not close cleanly, `oryxis vault unlock` recovers it"),
+        None => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("oryxis-vault-test-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("memory")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn seal_open_round_trip() {
+        let key = random_bytes(32);
+        let sealed = seal(&key, &aad("v1", "memory/memory.db"), b"hello vault").unwrap();
+        assert!(sealed.starts_with(MAGIC));
+        assert!(!sealed.windows(11).any(|w| w == b"hello vault"));
+        let opened = open_sealed(&key, &aad("v1", "memory/memory.db"), &sealed).unwrap();
+        assert_eq!(opened.as_slice(), b"hello vault");
+    }
+
+    #[test]
+    fn wrong_aad_or_key_is_rejected() {
+        let key = random_bytes(32);
+        let sealed = seal(&key, &aad("v1", "memory/memory.db"), b"secret").unwrap();
+        // Başka dosyanın yerine konmuş ya da başka vault'a ait
+        assert!(open_sealed(&key, &aad("v1", "memory/conversation.jsonl"), &sealed).is_err());
+        assert!(open_sealed(&key, &aad("v2", "memory/memory.db"), &sealed).is_err());
+        assert!(open_sealed(&random_bytes(32), &aad("v1", "memory/memory.db"), &sealed).is_err());
+
+        let mut tampered = sealed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(open_sealed(&key, &aad("v1", "memory/memory.db"), &tampered).is_err());
+    }
+
+    #[test]
+    fn finish_rotation_resumes_after_commit_marker() {
+        let root = scratch("committed");
+        std::fs::write(root.join(VAULT_PATH), "old").unwrap();
+        std::fs::write(enc_path(&root, SEALED[0]), "old data").unwrap();
+        std::fs::write(root.join(format!("{}.enc.{}", SEALED[0], ROTATE_SUFFIX)), "new data").unwrap();
+        std::fs::write(root.join(format!("{}.{}", VAULT_PATH, ROTATE_SUFFIX)), "new").unwrap();
+
+        finish_rotation(&root, true).unwrap();
+        assert_eq!(std::fs::read_to_string(root.join(VAULT_PATH)).unwrap(), "new");
+        assert_eq!(std::fs::read_to_string(enc_path(&root, SEALED[0])).unwrap(), "new data");
+        assert!(!root.join(format!("{}.{}", VAULT_PATH, ROTATE_SUFFIX)).exists());
+        assert!(!root.join(format!("{}.enc.{}", SEALED[0], ROTATE_SUFFIX)).exists());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn finish_rotation_discards_uncommitted_files() {
+        let root = scratch("uncommitted");
+        std::fs::write(root.join(VAULT_PATH), "old").unwrap();
+        std::fs::write(enc_path(&root, SEALED[0]), "old data").unwrap();
+        std::fs::write(root.join(format!("{}.enc.{}", SEALED[0], ROTATE_SUFFIX)), "new data").unwrap();
+
+        finish_rotation(&root, true).unwrap();
+        assert_eq!(std::fs::read_to_string(root.join(VAULT_PATH)).unwrap(), "old");
+        assert_eq!(std::fs::read_to_string(enc_path(&root, SEALED[0])).unwrap(), "old data");
+        assert!(!root.join(format!("{}.enc.{}", SEALED[0], ROTATE_SUFFIX)).exists());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,230 @@
+// Skill'lerin Python bağımlılıkları için Oryxis'e ait paket klasörü.
+// Sistem Python'una değil, `.oryxis/site-packages` hedef klasörüne kurulur
+// ve ilk execute'tan önce sys.path'e eklenir.
+
+use pyo3::prelude::*;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config;
+use crate::errors::OryxisError;
+use crate::skill_manifest;
+
+fn root() -> PathBuf {
+    std::env::current_dir().unwrap_or_default()
+}
+
+pub fn site_packages_dir() -> PathBuf {
+    root().join(".oryxis/site-packages")
+}
+
+fn wheelhouse_dir() -> PathBuf {
+    root().join(config::get().str("skills.wheelhouse").unwrap_or_default())
+}
+
+fn python_executable() -> String {
+    config::get().str("skills.python").unwrap_or_else(|| {
+        if cfg!(windows) { "python".to_string() } else { "python3".to_string() }
+    })
+}
+
+/// Embedded Python'da çalışan yardımcılar. `packaging` yoksa pip'in gömülü kopyası kullanılır.
+const HELPERS: &str = r#"
+try:
+    from packaging import requirements as _requirements, tags as _tags
+except ImportError:
+    from pip._vendor.packaging import requirements as _requirements, tags as _tags
+from importlib import metadata as _metadata
+import sys
+
+def unsatisfied(requirement):
+    req = _requirements.Requirement(requirement)
+    if req.marker is not None and not req.marker.evaluate():
+        return False
+    try:
+        version = _metadata.version(req.name)
+    except _metadata.PackageNotFoundError:
+        return True
+    return not req.specifier.contains(version, prereleases=True)
+
+def target():
+    tags = list(_tags.sys_tags())
+    unique = lambda items: list(dict.fromkeys(items))
+    return (
+        "%d.%d" % sys.version_info[:2],
+        _tags.interpreter_name(),
+        unique(t.abi for t in tags),
+        unique(t.platform for t in tags if t.platform != "any"),
+    )
+"#;
+
+/// Wheel seçimi için embedded Python: sürüm, implementation, ABI'ler, platformlar
+struct Target {
+    version: String,
+    implementation: String,
+    abis: Vec<String>,
+    platforms: Vec<String>,
+}
+
+fn helpers(py: Python<'_>) -> PyResult<Bound<'_, pyo3::types::PyDict>> {
+    let globals = pyo3::types::PyDict::new(py);
+    let code = std::ffi::CString::new(HELPERS).expect("helpers contain no NUL");
+    py.run(&code, Some(&globals), None)?;
+    Ok(globals)
+}
+
+/// "requests>=2.31" -> "requests"
+fn package_name(requirement: &str) -> &str {
+    let end = requirement
+        .find(|c: char| "<>=!~[; ".contains(c))
+        .unwrap_or(requirement.len());
+    requirement[..end].trim()
+}
+
+/// skills/*.yaml içindeki tüm `requirements` girdilerini toplar.
+pub fn collect_requirements() -> Result<Vec<(String, String)>, OryxisError> {
+    let mut seen = BTreeSet::new();
+    let mut reqs = Vec::new();
+    for (path, manifest) in skill_manifest::load_all(&skill_manifest::skills_dir())? {
+        let (name, requirements) = match manifest {
+            Ok(m) => (m.name, m.requirements),
+            // Manifest'in başka bir yeri bozuk diye bağımlılıkları kurulmadan kalmasın
+            Err(e) => {
+                eprintln!("[VENV] {}: {} (using its `requirements` as written)", path.display(), e);
+                raw_requirements(&path)
+            }
+        };
+        for req in requirements {
+            if seen.insert(req.clone()) {
+                reqs.push((name.clone(), req));
+            }
+        }
+    }
+    Ok(reqs)
+}
+
+/// Şemaya uymayan manifest'ten sadece `name` ve `requirements` (liste ya da tek string)
+fn raw_requirements(path: &Path) -> (String, Vec<String>) {
+    let raw: serde_yaml::Value = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_yaml::from_str(&s).ok())
+        .unwrap_or_default();
+    let name = raw["name"]
+        .as_str()
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default());
+    let requirements = match &raw["requirements"] {
+        serde_yaml::Value::Sequence(items) => items.iter().filter_map(|v| v.as_str().map(|s| s.trim().to_string())).collect(),
+        serde_yaml::Value::String(s) => vec![s.trim().to_string()],
+        _ => Vec::new(),
+    };
+    (name, requirements.into_iter().filter(|r: &String| !r.is_empty()).collect())
+}
+
+/// Kurulu olmayan requirement'ları döndürür (embedded Python'a göre).
+pub fn missing_requirements() -> Result<Vec<(String, String)>, OryxisError> {
+    let reqs = collect_requirements()?;
+    crate::executer::ensure_python_init();
+
+    Python::with_gil(|py| {
+        // Sürüm kısıtı da kontrol edilir: foo 1.0 kuruluyken `foo>=2` eksik sayılır
+        let unsatisfied = match helpers(py) {
+            Ok(globals) => globals.get_item("unsatisfied")?,
+            Err(e) => {
+                eprintln!("[VENV] packaging is not available ({}), only package names are checked", e);
+                None
+            }
+        };
+        let metadata = py.import("importlib.metadata")?;
+        let mut missing = Vec::new();
+        for (skill, req) in reqs {
+            let is_missing = match &unsatisfied {
+                Some(check) => check.call1((&req,))?.extract::<bool>()?,
+                None => metadata.call_method1("version", (package_name(&req),)).is_err(),
+            };
+            if is_missing {
+                missing.push((skill, req));
+            }
+        }
+        Ok(missing)
+    })
+    .map_err(|e: PyErr| OryxisError::PyEnvError(e.to_string()))
+}
+
+fn embedded_target() -> Result<Target, OryxisError> {
+    crate::executer::ensure_python_init();
+    Python::with_gil(|py| {
+        let (version, implementation, abis, platforms) = helpers(py)?.get_item("target")?.expect("defined in HELPERS").call0()?.extract()?;
+        Ok(Target { version, implementation, abis, platforms })
+    })
+    .map_err(|e: PyErr| OryxisError::PyEnvError(format!("cannot read the embedded Python's wheel tags: {}", e)))
+}
+
+/// Eksik paketleri yerel wheelhouse'tan (offline) hedef klasöre kurar.
+pub fn install_missing() -> Result<(), OryxisError> {
+    let missing = missing_requirements()?;
+    if missing.is_empty() {
+        println!("All skill requirements are installed.");
+        return Ok(());
+    }
+
+    let wheelhouse = wheelhouse_dir();
+    if !wheelhouse.is_dir() {
+        return Err(OryxisError::PyEnvError(format!(
+            "wheelhouse not found at {} (set WHEELHOUSE)",
+            wheelhouse.display()
+        )));
+    }
+
+    let target = site_packages_dir();
+    std::fs::create_dir_all(&target).map_err(|e| OryxisError::PyEnvError(e.to_string()))?;
+
+    let packages: Vec<&str> = missing.iter().map(|(_, req)| req.as_str()).collect();
+    println!("Installing {} package(s): {}", packages.len(), packages.join(", "));
+
+    // pip başka bir Python'la çalışabilir; wheel'ler embedded Python'un sürümü, ABI'si ve
+    // platformu için seçilir, yoksa derlenmiş paketler kurulup import edilemez
+    let embedded = embedded_target()?;
+    let mut command = Command::new(python_executable());
+    command
+        .args(["-m", "pip", "install", "--no-index", "--upgrade", "--only-binary=:all:"])
+        .arg("--find-links")
+        .arg(&wheelhouse)
+        .arg("--target")
+        .arg(&target)
+        .args(["--python-version", &embedded.version, "--implementation", &embedded.implementation]);
+    for abi in &embedded.abis {
+        command.args(["--abi", abi]);
+    }
+    for platform in &embedded.platforms {
+        command.args(["--platform", platform]);
+    }
+    let status = command
+        .args(&packages)
+        .status()
+        .map_err(|e| OryxisError::PyEnvError(format!("pip: {}", e)))?;
+
+    if !status.success() {
+        return Err(OryxisError::PyEnvError(format!("pip exited with {}", status)));
+    }
+    Ok(())
+}
+
+/// Hedef klasörü embedded Python'a ekler (.pth dosyaları dahil).
+pub fn activate(py: Python<'_>) -> PyResult<()> {
+    let target = site_packages_dir();
+    if !target.is_dir() {
+        return Ok(());
+    }
+    let site = py.import("site")?;
+    site.call_method1("addsitedir", (target.to_string_lossy().to_string(),))?;
+
+    // addsitedir sona ekler; sistem paketlerinin önüne al
+    let sys = py.import("sys")?;
+    let path = sys.getattr("path")?;
+    let target_str = target.to_string_lossy().to_string();
+    path.call_method1("remove", (&target_str,))?;
+    path.call_method1("insert", (0, &target_str))?;
+    Ok(())
+}
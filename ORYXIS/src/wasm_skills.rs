@@ -0,0 +1,269 @@
+// WASM skill'ler (wasmtime + WASI preview1) — native kütüphanelerle aynı describe/invoke
+// arayüzü, ama sandbox içinde: dosya sistemi sadece manifest'teki klasörler, ağ yok.
+// Üçüncü parti skill'ler güvenmeden kurulabilsin diye.
+//
+// Etkin izinler (permissions.rs):
+//     - "fs.read:data,docs"      → salt-okunur preopen
+//     - "fs.write:memory/notes"  → yazılabilir preopen
+//     - ağ                       → hiç yok. Preview1'de soket yok; izinli ağ için skill'lerin
+//                                  component (wasm32-wasip2, wasi:sockets + host allowlist)
+//                                  olarak yüklenmesi gerekir, bu ayrı bir iş olarak bekliyor
+//     - process.spawn            → WASI'de süreç yok, anlamsız
+//
+// Preopen içinden tek dosya gizlenemez: korunan bir yolu (vault anahtarı, .env, bkz.
+// permissions::protected) içeren ya da onun içindeki bir klasör izni olan wasm skill yüklenmez.
+//
+// fs izinleri guest yollarıyla oryxis_skill_configure'a da verilir; skill_abi::grants reddi ve
+// sandbox'ın kendi reddi (ENOTCAPABLE / EPERM) SecurityViolation olarak döner.
+//
+// Her çağrı yeni bir Store/Instance ile çalışır; çağrılar arası state tutulmaz.
+
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use wasmtime::{Config, Engine, Instance, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder, Trap};
+use wasmtime_wasi::preview1::{self, WasiP1Ctx};
+use wasmtime_wasi::{DirPerms, FilePerms, WasiCtxBuilder};
+
+use crate::errors::OryxisError;
+use crate::permissions::Permission;
+
+const MEMORY_LIMIT: usize = 256 << 20;
+const EPOCH_TICK: Duration = Duration::from_millis(100);
+
+static ENGINE: OnceLock<Engine> = OnceLock::new();
+
+/// Epoch interruption açık engine; arka plandaki ticker timeout'u sağlar.
+fn engine() -> &'static Engine {
+    ENGINE.get_or_init(|| {
+        let mut config = Config::new();
+        config.epoch_interruption(true);
+        let engine = Engine::new(&config).expect("wasmtime engine");
+
+        let ticker = engine.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(EPOCH_TICK);
+            ticker.increment_epoch();
+        });
+        engine
+    })
+}
+
+#[derive(Debug, Clone)]
+struct Preopen {
+    host: PathBuf,
+    guest: String,
+    writable: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Sandbox {
+    preopens: Vec<Preopen>,
+    /// Guest'teki skill_abi::grants için, guest yollarıyla ("fs.read:data")
+    grants: Vec<String>,
+}
+
+impl Sandbox {
+    fn from_permissions(permissions: &[Permission]) -> Result<Self, OryxisError> {
+        let mut sandbox = Sandbox::default();
+        let real = |p: &Path| p.canonicalize().unwrap_or_else(|_| p.to_path_buf());
+        let protected: Vec<PathBuf> = crate::permissions::protected().iter().map(|p| real(p)).collect();
+
+        for permission in permissions {
+            match permission.kind.as_str() {
+                "fs.read" | "fs.write" => {
+                    let host = real(&permission.host_path());
+                    if let Some(hit) = protected.iter().find(|p| p.starts_with(&host) || host.starts_with(p)) {
+                        return Err(OryxisError::InvalidSkill(format!(
+                            "`{}` would expose {} to a WASM skill; grant a narrower folder", permission, hit.display()
+                        )));
+                    }
+                    // Guest yolu manifest'te yazıldığı gibi ("*" → kök)
+                    let guest = if permission.item == "*" { "/".to_string() } else { permission.item.replace('\\', "/") };
+                    sandbox.grants.push(format!("{}:{}", permission.kind, if permission.item == "*" { "*" } else { &guest }));
+                    sandbox.preopens.push(Preopen {
+                        host: permission.host_path(),
+                        guest,
+                        writable: permission.kind == "fs.write",
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(sandbox)
+    }
+
+    fn wasi(&self) -> Result<WasiP1Ctx, OryxisError> {
+        let mut builder = WasiCtxBuilder::new();
+
+        for preopen in &self.preopens {
+            let (dir_perms, file_perms) = if preopen.writable {
+                (DirPerms::all(), FilePerms::all())
+            } else {
+                (DirPerms::READ, FilePerms::READ)
+            };
+            builder
+                .preopened_dir(&preopen.host, &preopen.guest, dir_perms, file_perms)
+                .map_err(|e| OryxisError::PluginError(format!("preopen {}: {}", preopen.host.display(), e)))?;
+        }
+
+        // Ağ yok: preview1'de soket yok, yine de izin kontrolü hep reddetsin
+        builder.socket_addr_check(|_, _| Box::pin(async { false }));
+        builder.allow_ip_name_lookup(false);
+
+        Ok(builder.build_p1())
+    }
+}
+
+struct State {
+    wasi: WasiP1Ctx,
+    limits: StoreLimits,
+}
+
+pub struct WasmSkill {
+    module: Module,
+    sandbox: Sandbox,
+}
+
+fn wasm_err(e: impl std::fmt::Display) -> OryxisError {
+    OryxisError::PluginError(format!("wasm: {}", e))
+}
+
+fn trap_err(e: wasmtime::Error, timeout: Duration) -> OryxisError {
+    match e.downcast_ref::<Trap>() {
+        Some(Trap::Interrupt) => OryxisError::PluginError(format!("wasm skill timed out after {}s", timeout.as_secs())),
+        _ => wasm_err(e),
+    }
+}
+
+/// wasmtime-wasi'nin senkron çağrıları içeride tokio block_on kullanıyor;
+/// runtime thread'inden çağrılırsa panikler, o yüzden ayrı thread'de çalıştırılır.
+fn off_runtime<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    std::thread::scope(|s| s.spawn(f).join().expect("wasm skill thread panicked"))
+}
+
+impl WasmSkill {
+    pub fn load(path: &Path, permissions: &[Permission]) -> Result<Self, OryxisError> {
+        let module = Module::from_file(engine(), path).map_err(|e| OryxisError::PluginError(format!("{}: {}", path.display(), e)))?;
+        let skill = WasmSkill { module, sandbox: Sandbox::from_permissions(permissions)? };
+
+        let version = off_runtime(|| {
+            let (mut store, instance) = skill.instantiate()?;
+            let abi_version = instance
+                .get_typed_func::<(), u32>(&mut store, "oryxis_skill_abi_version")
+                .map_err(|_| OryxisError::PluginError(format!("{}: not an Oryxis skill (missing oryxis_skill_abi_version)", path.display())))?;
+            abi_version.call(&mut store, ()).map_err(wasm_err)
+        })?;
+        if version != skill_abi::ABI_VERSION {
+            return Err(OryxisError::PluginError(format!(
+                "{}: skill ABI v{}, host expects v{}", path.display(), version, skill_abi::ABI_VERSION
+            )));
+        }
+        Ok(skill)
+    }
+
+    fn instantiate(&self) -> Result<(Store<State>, Instance), OryxisError> {
+        let mut linker: Linker<State> = Linker::new(engine());
+        preview1::add_to_linker_sync(&mut linker, |s: &mut State| &mut s.wasi).map_err(wasm_err)?;
+
+        let state = State {
+            wasi: self.sandbox.wasi()?,
+            limits: StoreLimitsBuilder::new().memory_size(MEMORY_LIMIT).build(),
+        };
+        let mut store = Store::new(engine(), state);
+        store.limiter(|s| &mut s.limits);
+        store.set_epoch_deadline(crate::action_executor::exec_timeout().as_millis() as u64 / EPOCH_TICK.as_millis() as u64);
+
+        let instance = linker.instantiate(&mut store, &self.module).map_err(wasm_err)?;
+        self.configure(&mut store, &instance)?;
+        Ok((store, instance))
+    }
+
+    /// Guest'in skill_abi::grants'ına izinleri verir (eski modüllerde export yok → sadece sandbox)
+    fn configure(&self, store: &mut Store<State>, instance: &Instance) -> Result<(), OryxisError> {
+        let Ok(configure) = instance.get_typed_func::<(u32, u32), ()>(&mut *store, "oryxis_skill_configure") else {
+            return Ok(());
+        };
+        let memory = memory(store, instance)?;
+        let grants = serde_json::to_string(&self.sandbox.grants).map_err(|e| OryxisError::JsonParseError(e.to_string()))?;
+        let (ptr, len) = write_string(store, instance, &memory, &grants)?;
+        configure.call(&mut *store, (ptr, len)).map_err(wasm_err)
+    }
+
+    pub fn describe(&self) -> Result<String, OryxisError> {
+        off_runtime(|| {
+            let (mut store, instance) = self.instantiate()?;
+            let memory = memory(&mut store, &instance)?;
+            let describe = instance
+                .get_typed_func::<(), u64>(&mut store, "oryxis_skill_describe")
+                .map_err(wasm_err)?;
+            let packed = describe.call(&mut store, ()).map_err(wasm_err)?;
+            take_string(&mut store, &instance, &memory, packed)
+        })
+    }
+
+    /// JSON argümanlarla çağırır, {"ok"}/{"error"} zarfını olduğu gibi döner.
+    pub fn invoke(&self, function: &str, args_json: &str) -> Result<String, OryxisError> {
+        let timeout = crate::action_executor::exec_timeout();
+        off_runtime(|| {
+            let (mut store, instance) = self.instantiate()?;
+            let memory = memory(&mut store, &instance)?;
+            let (f_ptr, f_len) = write_string(&mut store, &instance, &memory, function)?;
+            let (a_ptr, a_len) = write_string(&mut store, &instance, &memory, args_json)?;
+
+            let invoke = instance
+                .get_typed_func::<(u32, u32, u32, u32), u64>(&mut store, "oryxis_skill_invoke")
+                .map_err(wasm_err)?;
+            let packed = invoke
+                .call(&mut store, (f_ptr, f_len, a_ptr, a_len))
+                .map_err(|e| trap_err(e, timeout))?;
+            take_string(&mut store, &instance, &memory, packed).map(|raw| mark_denials(&raw))
+        })
+    }
+}
+
+/// Sandbox dışına erişim guest'te io hatası olarak döner (preopen'da yok → ENOTCAPABLE,
+/// salt-okunur preopen'a yazma → EPERM); bunlar da izin reddi olarak işaretlenir.
+fn mark_denials(raw: &str) -> String {
+    let Ok(Value::Object(mut envelope)) = serde_json::from_str::<Value>(raw) else { return raw.to_string() };
+    let denied = envelope.get("error").and_then(|e| e.as_str()).is_some_and(|e| {
+        ["Capabilities insufficient", "os error 76", "Operation not permitted", "os error 63"]
+            .iter()
+            .any(|marker| e.contains(marker))
+    });
+    if !denied || envelope.contains_key("kind") {
+        return raw.to_string();
+    }
+    envelope.insert("kind".to_string(), json!("permission"));
+    Value::Object(envelope).to_string()
+}
+
+fn memory(store: &mut Store<State>, instance: &Instance) -> Result<Memory, OryxisError> {
+    instance
+        .get_memory(&mut *store, "memory")
+        .ok_or_else(|| wasm_err("module does not export `memory`"))
+}
+
+fn write_string(store: &mut Store<State>, instance: &Instance, memory: &Memory, s: &str) -> Result<(u32, u32), OryxisError> {
+    let alloc = instance
+        .get_typed_func::<u32, u32>(&mut *store, "oryxis_skill_alloc")
+        .map_err(wasm_err)?;
+    let len = s.len() as u32;
+    let ptr = alloc.call(&mut *store, len).map_err(wasm_err)?;
+    memory.write(&mut *store, ptr as usize, s.as_bytes()).map_err(wasm_err)?;
+    Ok((ptr, len))
+}
+
+/// (ptr << 32) | len → String; bölge guest'e geri verilir.
+fn take_string(store: &mut Store<State>, instance: &Instance, memory: &Memory, packed: u64) -> Result<String, OryxisError> {
+    let (ptr, len) = ((packed >> 32) as u32, packed as u32);
+    let mut buf = vec![0u8; len as usize];
+    memory.read(&*store, ptr as usize, &mut buf).map_err(wasm_err)?;
+
+    if let Ok(free) = instance.get_typed_func::<(u32, u32), ()>(&mut *store, "oryxis_skill_free") {
+        let _ = free.call(&mut *store, (ptr, len));
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
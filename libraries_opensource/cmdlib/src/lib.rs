@@ -1,6 +1,31 @@
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::path::Path;
 use std::process::Command;
+use serde_json::Value;
+
+skill_abi::export_skill! {
+    name: "cmd_lib",
+    version: "2.0",
+    functions: [ run_command(cmd, args_list) ],
+    dispatch: dispatch,
+}
+
+fn dispatch(function: &str, args: &[Value]) -> Result<Value, String> {
+    match function {
+        "run_command" => {
+            let cmd = skill_abi::arg_str(args, 0, "cmd")?;
+            let cmd_args: Vec<String> = match args.get(1) {
+                Some(Value::Array(a)) => a.iter().map(|v| v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string())).collect(),
+                Some(Value::Null) | None => Vec::new(),
+                Some(other) => vec![other.as_str().map(|s| s.to_string()).unwrap_or_else(|| other.to_string())],
+            };
+            let program = skill_abi::grants::check_spawn(&cmd)?;
+            Ok(Value::String(run(&program, &cmd_args)))
+        }
+        _ => Err(format!("unknown function `{}`", function)),
+    }
+}
 
 #[no_mangle]
 pub extern "C" fn run_command(cmd: *const c_char, args_json: *const c_char) -> *mut c_char {
@@ -24,7 +49,14 @@ fn _run_command(cmd: *const c_char, args_json: *const c_char) -> String {
 
     let args: Vec<String> = serde_json::from_str(&args_str).unwrap_or_default();
 
-    match Command::new(&cmd_str).args(&args).output() {
+    match skill_abi::grants::check_spawn(&cmd_str) {
+        Ok(program) => run(&program, &args),
+        Err(e) => format!("ERROR: {}", e),
+    }
+}
+
+fn run(program: &Path, args: &[String]) -> String {
+    match Command::new(program).args(args).output() {
         Ok(output) => {
             if output.status.success() {
                 String::from_utf8_lossy(&output.stdout).to_string()
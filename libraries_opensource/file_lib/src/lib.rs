@@ -3,6 +3,53 @@ use std::os::raw::c_char;
 use std::fs;
 use std::io::{Read, Write};
 use sha2::{Sha256, Digest};
+use serde_json::{json, Value};
+use skill_abi::grants::{check_read, check_write};
+
+skill_abi::export_skill! {
+    name: "file_lib",
+    version: "2.0",
+    functions: [
+        read_file(path),
+        write_file(path, content),
+        append_to_file(path, content),
+        delete_file(path),
+        move_file(src, dst),
+        copy_file(src, dst),
+        list_directory(path),
+        create_directory(path),
+        delete_directory(path),
+        create_file(path),
+        path_exists(path),
+        is_file(path),
+        is_directory(path),
+        get_metadata(path),
+        get_file_hash(path),
+    ],
+    dispatch: dispatch,
+}
+
+fn dispatch(function: &str, args: &[Value]) -> Result<Value, String> {
+    let a = |i: usize, name: &str| skill_abi::arg_str(args, i, name);
+    match function {
+        "read_file" => read(&a(0, "path")?),
+        "write_file" => write(&a(0, "path")?, &a(1, "content")?),
+        "append_to_file" => append(&a(0, "path")?, &a(1, "content")?),
+        "delete_file" => remove_file(&a(0, "path")?),
+        "move_file" => rename(&a(0, "src")?, &a(1, "dst")?),
+        "copy_file" => copy(&a(0, "src")?, &a(1, "dst")?),
+        "list_directory" => list(&a(0, "path")?),
+        "create_directory" => create_dir(&a(0, "path")?),
+        "delete_directory" => remove_dir(&a(0, "path")?),
+        "create_file" => create(&a(0, "path")?),
+        "path_exists" => exists(&a(0, "path")?),
+        "is_file" => is_file_at(&a(0, "path")?),
+        "is_directory" => is_dir_at(&a(0, "path")?),
+        "get_metadata" => metadata(&a(0, "path")?),
+        "get_file_hash" => hash(&a(0, "path")?),
+        _ => Err(format!("unknown function `{}`", function)),
+    }
+}
 
 fn to_str(ptr: *const c_char) -> Option<String> {
     if ptr.is_null() { return None; }
@@ -13,162 +60,210 @@ fn ret(s: String) -> *mut c_char {
     CString::new(s).unwrap_or_default().into_raw()
 }
 
-fn ok_json(msg: &str) -> String { format!(r#"{{"status":"success","message":"{}"}}"#, msg) }
+fn ok_json(msg: &str) -> Value { json!({"status": "success", "message": msg}) }
 fn err_json(msg: &str) -> String { format!(r#"{{"status":"error","message":"{}"}}"#, msg.replace('"', "'")) }
 
+// Eski string ABI'si: string sonuç olduğu gibi, JSON sonuç serialize, hata err_json
+fn legacy(result: Result<Value, String>) -> *mut c_char {
+    ret(match result {
+        Ok(Value::String(s)) => s,
+        Ok(v) => v.to_string(),
+        Err(e) => err_json(&e),
+    })
+}
+
+fn read(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    fs::read_to_string(p).map(Value::String).map_err(|e| e.to_string())
+}
+
+fn write(p: &str, c: &str) -> Result<Value, String> {
+    check_write(p)?;
+    fs::write(p, c).map(|_| ok_json("written")).map_err(|e| e.to_string())
+}
+
+fn append(p: &str, c: &str) -> Result<Value, String> {
+    check_write(p)?;
+    let mut f = fs::OpenOptions::new().append(true).create(true).open(p).map_err(|e| e.to_string())?;
+    writeln!(f, "{}", c).map(|_| ok_json("appended")).map_err(|e| e.to_string())
+}
+
+fn remove_file(p: &str) -> Result<Value, String> {
+    check_write(p)?;
+    fs::remove_file(p).map(|_| ok_json("deleted")).map_err(|e| e.to_string())
+}
+
+fn rename(s: &str, d: &str) -> Result<Value, String> {
+    check_write(s)?;
+    check_write(d)?;
+    fs::rename(s, d).map(|_| ok_json("moved")).map_err(|e| e.to_string())
+}
+
+fn copy(s: &str, d: &str) -> Result<Value, String> {
+    check_read(s)?;
+    check_write(d)?;
+    fs::copy(s, d).map(|bytes| json!({"status": "success", "bytes": bytes})).map_err(|e| e.to_string())
+}
+
+fn list(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    let entries = fs::read_dir(p).map_err(|e| e.to_string())?;
+    let items: Vec<Value> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            let is_dir = e.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            let size = e.metadata().map(|m| m.len()).unwrap_or(0);
+            json!({"name": name, "is_dir": is_dir, "size": size})
+        })
+        .collect();
+    Ok(Value::Array(items))
+}
+
+fn create_dir(p: &str) -> Result<Value, String> {
+    check_write(p)?;
+    fs::create_dir_all(p).map(|_| ok_json("created")).map_err(|e| e.to_string())
+}
+
+fn remove_dir(p: &str) -> Result<Value, String> {
+    check_write(p)?;
+    fs::remove_dir_all(p).map(|_| ok_json("deleted")).map_err(|e| e.to_string())
+}
+
+fn create(p: &str) -> Result<Value, String> {
+    check_write(p)?;
+    fs::File::create(p).map(|_| ok_json("created")).map_err(|e| e.to_string())
+}
+
+fn exists(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    Ok(Value::Bool(fs::metadata(p).is_ok()))
+}
+
+fn is_file_at(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    Ok(Value::Bool(fs::metadata(p).map(|m| m.is_file()).unwrap_or(false)))
+}
+
+fn is_dir_at(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    Ok(Value::Bool(fs::metadata(p).map(|m| m.is_dir()).unwrap_or(false)))
+}
+
+fn metadata(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    let m = fs::metadata(p).map_err(|e| e.to_string())?;
+    let modified = m.modified()
+        .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    Ok(json!({"size": m.len(), "modified": modified, "is_file": m.is_file(), "is_dir": m.is_dir()}))
+}
+
+fn hash(p: &str) -> Result<Value, String> {
+    check_read(p)?;
+    let mut f = fs::File::open(p).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        match f.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => hasher.update(&buf[..n]),
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+    Ok(Value::String(format!("{:x}", hasher.finalize())))
+}
+
 #[no_mangle]
 pub extern "C" fn read_file(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(fs::read_to_string(&p).unwrap_or_else(|e| err_json(&e.to_string())))
+    legacy(read(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn write_file(path: *const c_char, content: *const c_char) -> *mut c_char {
     let (Some(p), Some(c)) = (to_str(path), to_str(content)) else { return ret(err_json("null arg")); };
-    ret(match fs::write(&p, &c) {
-        Ok(_) => ok_json("written"),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(write(&p, &c))
 }
 
 #[no_mangle]
 pub extern "C" fn append_to_file(path: *const c_char, content: *const c_char) -> *mut c_char {
     let (Some(p), Some(c)) = (to_str(path), to_str(content)) else { return ret(err_json("null arg")); };
-    ret(match fs::OpenOptions::new().append(true).create(true).open(&p) {
-        Ok(mut f) => match writeln!(f, "{}", c) {
-            Ok(_) => ok_json("appended"),
-            Err(e) => err_json(&e.to_string()),
-        },
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(append(&p, &c))
 }
 
 #[no_mangle]
 pub extern "C" fn delete_file(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::remove_file(&p) {
-        Ok(_) => ok_json("deleted"),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(remove_file(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn move_file(src: *const c_char, dst: *const c_char) -> *mut c_char {
     let (Some(s), Some(d)) = (to_str(src), to_str(dst)) else { return ret(err_json("null arg")); };
-    ret(match fs::rename(&s, &d) {
-        Ok(_) => ok_json("moved"),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(rename(&s, &d))
 }
 
 #[no_mangle]
 pub extern "C" fn copy_file(src: *const c_char, dst: *const c_char) -> *mut c_char {
     let (Some(s), Some(d)) = (to_str(src), to_str(dst)) else { return ret(err_json("null arg")); };
-    ret(match fs::copy(&s, &d) {
-        Ok(bytes) => format!(r#"{{"status":"success","bytes":{}}}"#, bytes),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(copy(&s, &d))
 }
 
 #[no_mangle]
 pub extern "C" fn list_directory(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::read_dir(&p) {
-        Ok(entries) => {
-            let items: Vec<String> = entries
-                .filter_map(|e| e.ok())
-                .map(|e| {
-                    let name = e.file_name().to_string_lossy().to_string();
-                    let is_dir = e.file_type().map(|t| t.is_dir()).unwrap_or(false);
-                    let size = e.metadata().map(|m| m.len()).unwrap_or(0);
-                    format!(r#"{{"name":"{}","is_dir":{},"size":{}}}"#, name, is_dir, size)
-                })
-                .collect();
-            format!("[{}]", items.join(","))
-        }
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(list(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn create_directory(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::create_dir_all(&p) {
-        Ok(_) => ok_json("created"),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(create_dir(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn delete_directory(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::remove_dir_all(&p) {
-        Ok(_) => ok_json("deleted"),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(remove_dir(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn create_file(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::File::create(&p) {
-        Ok(_) => ok_json("created"),
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(create(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn path_exists(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret("false".to_string()); };
-    ret(fs::metadata(&p).is_ok().to_string())
+    ret(exists(&p).map(|v| v.to_string()).unwrap_or_else(|_| "false".to_string()))
 }
 
 #[no_mangle]
 pub extern "C" fn is_file(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret("false".to_string()); };
-    ret(fs::metadata(&p).map(|m| m.is_file()).unwrap_or(false).to_string())
+    ret(is_file_at(&p).map(|v| v.to_string()).unwrap_or_else(|_| "false".to_string()))
 }
 
 #[no_mangle]
 pub extern "C" fn is_directory(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret("false".to_string()); };
-    ret(fs::metadata(&p).map(|m| m.is_dir()).unwrap_or(false).to_string())
+    ret(is_dir_at(&p).map(|v| v.to_string()).unwrap_or_else(|_| "false".to_string()))
 }
 
 #[no_mangle]
 pub extern "C" fn get_metadata(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::metadata(&p) {
-        Ok(m) => {
-            let modified = m.modified()
-                .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_secs();
-            format!(r#"{{"size":{},"modified":{},"is_file":{},"is_dir":{}}}"#, m.len(), modified, m.is_file(), m.is_dir())
-        }
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(metadata(&p))
 }
 
 #[no_mangle]
 pub extern "C" fn get_file_hash(path: *const c_char) -> *mut c_char {
     let Some(p) = to_str(path) else { return ret(err_json("null path")); };
-    ret(match fs::File::open(&p) {
-        Ok(mut f) => {
-            let mut hasher = Sha256::new();
-            let mut buf = [0u8; 4096];
-            loop {
-                match f.read(&mut buf) {
-                    Ok(0) => break,
-                    Ok(n) => hasher.update(&buf[..n]),
-                    Err(e) => return ret(err_json(&e.to_string())),
-                }
-            }
-            format!("{:x}", hasher.finalize())
-        }
-        Err(e) => err_json(&e.to_string()),
-    })
+    legacy(hash(&p))
 }
 
 #[no_mangle]
@@ -176,4 +271,4 @@ pub extern "C" fn free_string(ptr: *mut c_char) {
     if !ptr.is_null() {
         unsafe { let _ = CString::from_raw(ptr); }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,305 @@
+// memorylib — konuşmalar arası kalıcı hafıza, SQLite (memory/memory.db).
+//
+// Her kayıt: key (tekil), value, category, importance (0..1), oluşturma/güncelleme
+// zamanı, kaydı yazan session (host ORYXIS_SESSION ile verir), kaynağı (`source`:
+// konuşmadan otomatik çıkarıldıysa hangi turlar ve kanıt cümlesi) ve erişim sayacı.
+// search() anahtar, değer ve kategori üzerinde bulanık eşleşme yapar; yazım hataları
+// (spotfy → spotify) bigram benzerliğiyle yakalanır, sonuç importance ile ağırlıklanır.
+//
+// Veritabanı yolu ORYXIS_MEMORY_DB ile değiştirilebilir; yazma izni (fs.write) gerekir.
+
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+skill_abi::export_skill! {
+    name: "memorylib",
+    version: "1.2",
+    functions: [
+        remember(key, value, category, importance, source),
+        recall(key),
+        search(query, category, limit),
+        search_memory(query, category, limit),
+        forget(key),
+        list_all(category),
+    ],
+    dispatch: dispatch,
+}
+
+const DEFAULT_CATEGORY: &str = "general";
+const DEFAULT_IMPORTANCE: f64 = 0.5;
+const DEFAULT_LIMIT: usize = 10;
+// Bu eşiğin altındaki eşleşmeler gürültü
+const MIN_MATCH: f64 = 0.5;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS memories (
+    key            TEXT PRIMARY KEY,
+    value          TEXT NOT NULL,
+    category       TEXT NOT NULL DEFAULT 'general',
+    importance     REAL NOT NULL DEFAULT 0.5,
+    source_session TEXT,
+    source         TEXT,
+    created_at     INTEGER NOT NULL,
+    updated_at     INTEGER NOT NULL,
+    accessed_at    INTEGER,
+    access_count   INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX IF NOT EXISTS memories_category ON memories(category);
+";
+
+const COLUMNS: &str = "key, value, category, importance, source_session, source, created_at, updated_at, access_count";
+
+fn dispatch(function: &str, args: &[Value]) -> Result<Value, String> {
+    let a = |i: usize, name: &str| skill_abi::arg_str(args, i, name);
+    match function {
+        "remember" => remember(&a(0, "key")?, &a(1, "value")?, opt_str(args, 2), importance(args.get(3))?, opt_str(args, 4)),
+        "recall" => recall(&a(0, "key")?),
+        "search" | "search_memory" => search(&a(0, "query")?, opt_str(args, 1), limit(args.get(2))?),
+        "forget" => forget(&a(0, "key")?),
+        "list_all" => list_all(opt_str(args, 0)),
+        _ => Err(format!("unknown function `{}`", function)),
+    }
+}
+
+/// None / boş string → varsayılan
+fn opt_str(args: &[Value], i: usize) -> Option<String> {
+    match args.get(i) {
+        Some(Value::String(s)) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Some(Value::Null) | None | Some(Value::String(_)) => None,
+        Some(other) => Some(other.to_string()),
+    }
+}
+
+fn number(value: Option<&Value>, name: &str) -> Result<Option<f64>, String> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Number(n)) => Ok(n.as_f64()),
+        Some(Value::String(s)) => s.trim().parse().map(Some).map_err(|_| format!("`{}` must be a number, got `{}`", name, s)),
+        Some(other) => Err(format!("`{}` must be a number, got {}", name, other)),
+    }
+}
+
+fn importance(value: Option<&Value>) -> Result<Option<f64>, String> {
+    match number(value, "importance")? {
+        Some(i) if !(0.0..=1.0).contains(&i) => Err(format!("importance must be between 0 and 1, got {}", i)),
+        other => Ok(other),
+    }
+}
+
+fn limit(value: Option<&Value>) -> Result<usize, String> {
+    match number(value, "limit")? {
+        Some(n) if n < 1.0 => Err(format!("limit must be at least 1, got {}", n)),
+        Some(n) => Ok(n as usize),
+        None => Ok(DEFAULT_LIMIT),
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or_default()
+}
+
+fn db_path() -> PathBuf {
+    std::env::var_os("ORYXIS_MEMORY_DB")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("memory/memory.db"))
+}
+
+fn open() -> Result<Connection, String> {
+    let path = db_path();
+    skill_abi::grants::check_write(&path.to_string_lossy())?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+    }
+    let conn = Connection::open(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5)).map_err(|e| e.to_string())?;
+    conn.execute_batch(SCHEMA).map_err(|e| e.to_string())?;
+    migrate(&conn).map_err(|e| e.to_string())?;
+    Ok(conn)
+}
+
+/// 1.1 veritabanlarında `source` kolonu yok
+fn migrate(conn: &Connection) -> rusqlite::Result<()> {
+    let has_source = conn
+        .prepare("SELECT 1 FROM pragma_table_info('memories') WHERE name = 'source'")?
+        .exists([])?;
+    if !has_source {
+        conn.execute_batch("ALTER TABLE memories ADD COLUMN source TEXT")?;
+    }
+    Ok(())
+}
+
+fn entry(row: &Row) -> rusqlite::Result<Value> {
+    Ok(json!({
+        "key": row.get::<_, String>(0)?,
+        "value": row.get::<_, String>(1)?,
+        "category": row.get::<_, String>(2)?,
+        "importance": row.get::<_, f64>(3)?,
+        "source_session": row.get::<_, Option<String>>(4)?,
+        "source": row.get::<_, Option<String>>(5)?,
+        "created_at": row.get::<_, i64>(6)?,
+        "updated_at": row.get::<_, i64>(7)?,
+        "access_count": row.get::<_, i64>(8)?,
+    }))
+}
+
+/// Var olan anahtarda değer güncellenir; verilmeyen category/importance korunur.
+fn remember(
+    key: &str,
+    value: &str,
+    category: Option<String>,
+    importance: Option<f64>,
+    source: Option<String>,
+) -> Result<Value, String> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("key must not be empty".to_string());
+    }
+    let conn = open()?;
+    let session = std::env::var("ORYXIS_SESSION").ok().filter(|s| !s.is_empty());
+    let ts = now();
+
+    let existed: bool = conn
+        .query_row("SELECT 1 FROM memories WHERE key = ?1", [key], |_| Ok(()))
+        .optional()
+        .map_err(|e| e.to_string())?
+        .is_some();
+
+    conn.execute(
+        "INSERT INTO memories (key, value, category, importance, source_session, source, created_at, updated_at)
+         VALUES (?1, ?2, COALESCE(?3, ?7), COALESCE(?4, ?8), ?5, ?9, ?6, ?6)
+         ON CONFLICT(key) DO UPDATE SET
+             value = excluded.value,
+             category = COALESCE(?3, category),
+             importance = COALESCE(?4, importance),
+             source_session = excluded.source_session,
+             source = excluded.source,
+             updated_at = excluded.updated_at",
+        params![key, value, category, importance, session, ts, DEFAULT_CATEGORY, DEFAULT_IMPORTANCE, source],
+    )
+    .map_err(|e| e.to_string())?;
+
+    Ok(json!({ "status": if existed { "updated" } else { "saved" }, "key": key }))
+}
+
+/// Değeri döner (yoksa None); erişim sayacı artar.
+fn recall(key: &str) -> Result<Value, String> {
+    let conn = open()?;
+    let value: Option<String> = conn
+        .query_row("SELECT value FROM memories WHERE key = ?1", [key.trim()], |row| row.get(0))
+        .optional()
+        .map_err(|e| e.to_string())?;
+    if value.is_some() {
+        conn.execute(
+            "UPDATE memories SET accessed_at = ?2, access_count = access_count + 1 WHERE key = ?1",
+            params![key.trim(), now()],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    Ok(value.map(Value::String).unwrap_or(Value::Null))
+}
+
+fn forget(key: &str) -> Result<Value, String> {
+    let conn = open()?;
+    let removed = conn.execute("DELETE FROM memories WHERE key = ?1", [key.trim()]).map_err(|e| e.to_string())?;
+    Ok(Value::Bool(removed > 0))
+}
+
+fn list_all(category: Option<String>) -> Result<Value, String> {
+    let conn = open()?;
+    let sql = format!(
+        "SELECT {} FROM memories WHERE ?1 IS NULL OR category = ?1 ORDER BY category, importance DESC, key",
+        COLUMNS
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([category], entry)
+        .map_err(|e| e.to_string())?
+        .collect::<rusqlite::Result<Vec<Value>>>()
+        .map_err(|e| e.to_string())?;
+    Ok(Value::Array(rows))
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_string())
+        .collect()
+}
+
+fn bigrams(word: &str) -> HashSet<(char, char)> {
+    let chars: Vec<char> = word.chars().collect();
+    chars.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+/// Dice katsayısı; kısa kelimelerde sadece tam/önek eşleşmesi sayılır
+fn similarity(token: &str, word: &str) -> f64 {
+    if word.contains(token) {
+        return 1.0;
+    }
+    if token.chars().count() < 3 || word.chars().count() < 3 {
+        return 0.0;
+    }
+    let (a, b) = (bigrams(token), bigrams(word));
+    let common = a.intersection(&b).count();
+    2.0 * common as f64 / (a.len() + b.len()) as f64
+}
+
+/// 0..1 — tüm sorgu alt string olarak geçiyorsa tam puan, yoksa kelime bazında en iyi eşleşmelerin ortalaması
+fn match_score(query: &str, key: &str, value: &str, category: &str) -> f64 {
+    let query_lower = query.to_lowercase();
+    let key_lower = key.to_lowercase();
+    if key_lower.contains(&query_lower) {
+        return 1.0;
+    }
+    if value.to_lowercase().contains(&query_lower) || category.to_lowercase() == query_lower {
+        return 0.9;
+    }
+
+    let tokens = words(query);
+    if tokens.is_empty() {
+        return 0.0;
+    }
+    let haystack: Vec<String> = [key, value, category].iter().flat_map(|t| words(t)).collect();
+    let total: f64 = tokens
+        .iter()
+        .map(|token| haystack.iter().map(|word| similarity(token, word)).fold(0.0, f64::max))
+        .sum();
+    total / tokens.len() as f64 * 0.85
+}
+
+fn search(query: &str, category: Option<String>, limit: usize) -> Result<Value, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return list_all(category);
+    }
+    let conn = open()?;
+    let sql = format!("SELECT {} FROM memories WHERE ?1 IS NULL OR category = ?1", COLUMNS);
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map([category], entry)
+        .map_err(|e| e.to_string())?
+        .collect::<rusqlite::Result<Vec<Value>>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut scored: Vec<(f64, Value)> = rows
+        .into_iter()
+        .filter_map(|mut row| {
+            let text = |f: &str| row[f].as_str().unwrap_or_default().to_string();
+            let m = match_score(query, &text("key"), &text("value"), &text("category"));
+            if m < MIN_MATCH {
+                return None;
+            }
+            let importance = row["importance"].as_f64().unwrap_or(DEFAULT_IMPORTANCE);
+            let score = m * (0.75 + 0.25 * importance);
+            row["score"] = json!((score * 1000.0).round() / 1000.0);
+            Some((score, row))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored.truncate(limit);
+    Ok(Value::Array(scored.into_iter().map(|(_, row)| row).collect()))
+}
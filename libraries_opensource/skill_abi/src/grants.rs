@@ -0,0 +1,204 @@
+// Host'un verdiği izinler (oryxis_skill_configure). Skill fonksiyonları işlem yapmadan
+// önce `check_*` çağırır; red `deny` ile işaretlenir, host bunu SecurityViolation'a çevirir.
+//
+// İzin string'leri:
+//   fs.read:<path>          fs.write:<path>  (write okumayı da kapsar, "*" her yer)
+//   process.spawn:<program> (PATH'te çözülür, "*" hepsi)
+//   fs.deny:<path>          host'un koruduğu yol (vault anahtarı, .env); her izni ezer
+//
+// configure hiç çağrılmadıysa (eski ctypes yüklemesi) her şey reddedilir.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::RwLock;
+
+pub const DENIED_PREFIX: &str = "permission denied: ";
+
+#[derive(Debug, Clone, Default)]
+pub struct Grants {
+    pub fs_read: Vec<String>,
+    pub fs_write: Vec<String>,
+    pub process_spawn: Vec<String>,
+    pub fs_deny: Vec<String>,
+}
+
+static GRANTS: RwLock<Option<Grants>> = RwLock::new(None);
+
+/// Host'tan gelen JSON: ["fs.read:/home/u", "process.spawn:git", ...]
+pub fn configure_json(json: &str) -> Result<(), String> {
+    let list: Vec<String> = serde_json::from_str(json).map_err(|e| format!("invalid grants json: {}", e))?;
+    let mut grants = Grants::default();
+    for permission in list {
+        let Some((kind, item)) = permission.split_once(':') else { continue };
+        let item = item.trim().to_string();
+        match kind.trim() {
+            "fs.read" => grants.fs_read.push(item),
+            "fs.write" => grants.fs_write.push(item),
+            "process.spawn" => grants.process_spawn.push(item),
+            "fs.deny" => grants.fs_deny.push(item),
+            _ => {}
+        }
+    }
+    *GRANTS.write().unwrap_or_else(|e| e.into_inner()) = Some(grants);
+    Ok(())
+}
+
+pub fn deny(msg: impl std::fmt::Display) -> String {
+    format!("{}{}", DENIED_PREFIX, msg)
+}
+
+pub fn is_denied(err: &str) -> bool {
+    err.starts_with(DENIED_PREFIX)
+}
+
+fn not_configured() -> String {
+    deny("the host did not configure this library's permissions")
+}
+
+/// `..` ve symlink'leri çözer; dosya henüz yoksa var olan en yakın üst klasörden kurar.
+fn resolve(path: &Path) -> PathBuf {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().unwrap_or_default().join(path)
+    };
+
+    let mut existing = absolute.as_path();
+    let mut rest = Vec::new();
+    loop {
+        if let Ok(canonical) = existing.canonicalize() {
+            let mut resolved = canonical;
+            for part in rest.iter().rev() {
+                resolved.push(part);
+            }
+            return resolved;
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                rest.push(name.to_os_string());
+                existing = parent;
+            }
+            _ => break,
+        }
+    }
+
+    // Hiçbir parçası yok: sadece sözdizimsel normalize
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::CurDir => {}
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+fn within(path: &Path, roots: &[&String]) -> bool {
+    let target = resolve(path);
+    roots.iter().any(|root| root.as_str() == "*" || target.starts_with(resolve(Path::new(root.as_str()))))
+}
+
+/// Korunan yol ya da içindeki bir şey mi (symlink'ler çözülmüş olarak). Yazmada korunanı
+/// içeren klasör de sayılır: üst klasörü silmek/taşımak korunanı da götürür.
+fn protected(grants: &Grants, path: &Path, write: bool) -> bool {
+    let target = resolve(path);
+    grants.fs_deny.iter().map(|p| resolve(Path::new(p))).any(|p| target.starts_with(&p) || (write && p.starts_with(&target)))
+}
+
+pub fn check_read(path: &str) -> Result<(), String> {
+    let guard = GRANTS.read().unwrap_or_else(|e| e.into_inner());
+    let Some(grants) = guard.as_ref() else { return Err(not_configured()) };
+    if protected(grants, Path::new(path), false) {
+        return Err(deny(format!("`{}` is protected by Oryxis", path)));
+    }
+    let roots: Vec<&String> = grants.fs_read.iter().chain(grants.fs_write.iter()).collect();
+    if within(Path::new(path), &roots) {
+        Ok(())
+    } else {
+        Err(deny(format!("read access to `{}` is not granted (fs.read)", path)))
+    }
+}
+
+pub fn check_write(path: &str) -> Result<(), String> {
+    let guard = GRANTS.read().unwrap_or_else(|e| e.into_inner());
+    let Some(grants) = guard.as_ref() else { return Err(not_configured()) };
+    if protected(grants, Path::new(path), true) {
+        return Err(deny(format!("`{}` is protected by Oryxis", path)));
+    }
+    let roots: Vec<&String> = grants.fs_write.iter().collect();
+    if within(Path::new(path), &roots) {
+        Ok(())
+    } else {
+        Err(deny(format!("write access to `{}` is not granted (fs.write)", path)))
+    }
+}
+
+/// Programın çalışacak dosyası: yol verildiyse kendisi, yoksa PATH'teki ilk eşleşme
+/// (Windows'ta PATHEXT uzantılarıyla). Symlink'ler çözülür; bulunamazsa None.
+pub fn resolve_program(program: &str) -> Option<PathBuf> {
+    let path = Path::new(program);
+    if program.is_empty() {
+        return None;
+    }
+    let candidates: Vec<PathBuf> = if path.is_absolute() || path.components().count() > 1 {
+        vec![std::env::current_dir().unwrap_or_default().join(path)]
+    } else {
+        std::env::var_os("PATH")
+            .map(|paths| std::env::split_paths(&paths).map(|dir| dir.join(path)).collect())
+            .unwrap_or_default()
+    };
+
+    let extensions: Vec<String> = if cfg!(windows) && path.extension().is_none() {
+        std::env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string())
+            .split(';')
+            .map(|ext| ext.trim_start_matches('.').to_string())
+            .filter(|ext| !ext.is_empty())
+            .collect()
+    } else {
+        vec![String::new()]
+    };
+
+    candidates.iter().find_map(|candidate| {
+        extensions.iter().find_map(|ext| {
+            let file = if ext.is_empty() { candidate.clone() } else { candidate.with_extension(ext) };
+            if is_executable(&file) {
+                file.canonicalize().ok()
+            } else {
+                None
+            }
+        })
+    })
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Program ve izinler PATH üzerinden tam yola çözülüp karşılaştırılır: `git` izni sadece
+/// PATH'teki git'i açar, /tmp/evil/git'i değil. Dönen yol çalıştırılmalı (arada PATH değişmesin).
+pub fn check_spawn(program: &str) -> Result<PathBuf, String> {
+    let guard = GRANTS.read().unwrap_or_else(|e| e.into_inner());
+    let Some(grants) = guard.as_ref() else { return Err(not_configured()) };
+    let Some(resolved) = resolve_program(program) else {
+        return Err(deny(format!("`{}` is not in the process.spawn allowlist", program)));
+    };
+    let allowed = grants
+        .process_spawn
+        .iter()
+        .any(|p| p == "*" || resolve_program(p).is_some_and(|granted| granted == resolved));
+    if allowed {
+        Ok(resolved)
+    } else {
+        Err(deny(format!("`{}` ({}) is not in the process.spawn allowlist", program, resolved.display())))
+    }
+}
@@ -0,0 +1,273 @@
+// Oryxis native skill ABI — her skill kütüphanesi aynı 4 sembolü export eder:
+//
+//   oryxis_skill_abi_version() -> u32
+//   oryxis_skill_describe() -> *mut c_char                      JSON: {name, version, abi, functions}
+//   oryxis_skill_invoke(function, args_json) -> *mut c_char     JSON in: [args...], out: {"ok": v} | {"error": msg}
+//   oryxis_skill_free(ptr)                                      describe/invoke sonucunu serbest bırakır
+//   oryxis_skill_configure(grants_json)                         izinler, bkz. grants.rs
+//
+// İzin reddi {"error": msg, "kind": "permission"} olarak döner.
+//
+// Skill tarafında `export_skill!` makrosu bu sembolleri üretir.
+//
+// wasm32 hedefinde (sandbox'lı WASM skill'ler) pointer yerine guest belleği kullanılır:
+//
+//   oryxis_skill_alloc(len) -> ptr                              host string yazmak için yer ayırır
+//   oryxis_skill_describe() -> u64                              (ptr << 32) | len
+//   oryxis_skill_invoke(f_ptr, f_len, a_ptr, a_len) -> u64      (ptr << 32) | len, aynı JSON zarfı
+//   oryxis_skill_free(ptr, len)
+//   oryxis_skill_configure(ptr, len)                            izinler, guest yollarıyla
+
+use serde_json::{json, Value};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+pub mod grants;
+
+pub const ABI_VERSION: u32 = 1;
+
+pub const SYM_ABI_VERSION: &[u8] = b"oryxis_skill_abi_version";
+pub const SYM_DESCRIBE: &[u8] = b"oryxis_skill_describe";
+pub const SYM_INVOKE: &[u8] = b"oryxis_skill_invoke";
+pub const SYM_FREE: &[u8] = b"oryxis_skill_free";
+pub const SYM_ALLOC: &[u8] = b"oryxis_skill_alloc";
+pub const SYM_CONFIGURE: &[u8] = b"oryxis_skill_configure";
+
+pub type AbiVersionFn = unsafe extern "C" fn() -> u32;
+pub type DescribeFn = unsafe extern "C" fn() -> *mut c_char;
+pub type InvokeFn = unsafe extern "C" fn(*const c_char, *const c_char) -> *mut c_char;
+pub type FreeFn = unsafe extern "C" fn(*mut c_char);
+pub type ConfigureFn = unsafe extern "C" fn(*const c_char);
+
+/// Export edilen bir fonksiyonun adı ve pozisyonel parametre adları
+pub struct FunctionDesc {
+    pub name: &'static str,
+    pub params: &'static [&'static str],
+}
+
+pub fn into_raw(s: String) -> *mut c_char {
+    CString::new(s).unwrap_or_default().into_raw()
+}
+
+pub fn describe_json(name: &str, version: &str, functions: &[FunctionDesc]) -> String {
+    let functions: Vec<Value> = functions
+        .iter()
+        .map(|f| json!({ "name": f.name, "params": f.params }))
+        .collect();
+    json!({ "name": name, "version": version, "abi": ABI_VERSION, "functions": functions }).to_string()
+}
+
+/// Pointer'ları çözer, `dispatch`'i çağırır, sonucu {"ok"}/{"error"} zarfına koyar.
+///
+/// # Safety
+/// `function` ve `args_json` null veya geçerli, NUL ile biten C string olmalı.
+pub unsafe fn invoke_json(
+    function: *const c_char,
+    args_json: *const c_char,
+    dispatch: impl Fn(&str, &[Value]) -> Result<Value, String>,
+) -> String {
+    let function = if function.is_null() {
+        return json!({ "error": "null function name" }).to_string();
+    } else {
+        match CStr::from_ptr(function).to_str() {
+            Ok(f) => f,
+            Err(_) => return json!({ "error": "invalid function name utf8" }).to_string(),
+        }
+    };
+
+    let args_json = if args_json.is_null() {
+        ""
+    } else {
+        match CStr::from_ptr(args_json).to_str() {
+            Ok(a) => a,
+            Err(_) => return json!({ "error": "invalid args utf8" }).to_string(),
+        }
+    };
+
+    invoke_str(function, args_json, dispatch)
+}
+
+/// `invoke_json`'un pointer'sız hali; boş `args_json` argümansız çağrıdır.
+pub fn invoke_str(
+    function: &str,
+    args_json: &str,
+    dispatch: impl Fn(&str, &[Value]) -> Result<Value, String>,
+) -> String {
+    let args: Vec<Value> = if args_json.trim().is_empty() {
+        Vec::new()
+    } else {
+        match serde_json::from_str::<Value>(args_json) {
+            Ok(Value::Array(a)) => a,
+            Ok(Value::Null) => Vec::new(),
+            Ok(other) => vec![other],
+            Err(e) => return json!({ "error": format!("invalid args json: {}", e) }).to_string(),
+        }
+    };
+
+    match dispatch(function, &args) {
+        Ok(v) => json!({ "ok": v }).to_string(),
+        Err(e) if grants::is_denied(&e) => json!({ "error": e, "kind": "permission" }).to_string(),
+        Err(e) => json!({ "error": e }).to_string(),
+    }
+}
+
+/// # Safety
+/// `ptr` bu ABI tarafından `into_raw` ile üretilmiş olmalı.
+pub unsafe fn free(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        let _ = CString::from_raw(ptr);
+    }
+}
+
+/// wasm32 guest tarafı: string'ler guest belleğinde (ptr, len) olarak taşınır.
+pub mod wasm {
+    /// Sonuç string'ini belleğe bırakır, (ptr << 32) | len döner. Host `free` ile geri verir.
+    pub fn pack(s: String) -> u64 {
+        let bytes = s.into_bytes().into_boxed_slice();
+        let len = bytes.len() as u64;
+        let ptr = Box::into_raw(bytes) as *mut u8 as usize as u64;
+        (ptr << 32) | len
+    }
+
+    pub fn alloc(len: u32) -> u32 {
+        let bytes = vec![0u8; len as usize].into_boxed_slice();
+        Box::into_raw(bytes) as *mut u8 as usize as u32
+    }
+
+    /// # Safety
+    /// `ptr`/`len` bu modülün `alloc` veya `pack`'inden gelmiş olmalı.
+    pub unsafe fn free(ptr: u32, len: u32) {
+        if ptr != 0 {
+            let slice = std::ptr::slice_from_raw_parts_mut(ptr as usize as *mut u8, len as usize);
+            drop(Box::from_raw(slice));
+        }
+    }
+
+    /// # Safety
+    /// `ptr`/`len` host'un `alloc` ile ayırıp doldurduğu bölge olmalı.
+    pub unsafe fn read(ptr: u32, len: u32) -> String {
+        if ptr == 0 {
+            return String::new();
+        }
+        let bytes = std::slice::from_raw_parts(ptr as usize as *const u8, len as usize);
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// i. argümanı string olarak al
+pub fn arg_str(args: &[Value], i: usize, name: &str) -> Result<String, String> {
+    match args.get(i) {
+        Some(Value::String(s)) => Ok(s.clone()),
+        Some(Value::Null) | None => Err(format!("missing argument `{}`", name)),
+        Some(other) => Ok(other.to_string()),
+    }
+}
+
+/// Skill'in ABI sembollerini üretir.
+///
+/// ```ignore
+/// skill_abi::export_skill! {
+///     name: "file_lib",
+///     version: "2.0",
+///     functions: [ read_file(path), write_file(path, content) ],
+///     dispatch: dispatch,
+/// }
+/// ```
+#[macro_export]
+macro_rules! export_skill {
+    (
+        name: $name:expr,
+        version: $version:expr,
+        functions: [ $( $fname:ident ( $( $param:ident ),* ) ),* $(,)? ],
+        dispatch: $dispatch:path $(,)?
+    ) => {
+        #[no_mangle]
+        pub extern "C" fn oryxis_skill_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub extern "C" fn oryxis_skill_describe() -> *mut std::os::raw::c_char {
+            $crate::into_raw(__oryxis_describe())
+        }
+
+        /// # Safety
+        /// İki argüman da null veya geçerli, NUL ile biten C string olmalı.
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn oryxis_skill_invoke(
+            function: *const std::os::raw::c_char,
+            args_json: *const std::os::raw::c_char,
+        ) -> *mut std::os::raw::c_char {
+            $crate::into_raw($crate::invoke_json(function, args_json, $dispatch))
+        }
+
+        /// # Safety
+        /// `ptr` oryxis_skill_describe/oryxis_skill_invoke'tan dönmüş olmalı.
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn oryxis_skill_free(ptr: *mut std::os::raw::c_char) {
+            $crate::free(ptr)
+        }
+
+        /// # Safety
+        /// `grants_json` null veya geçerli, NUL ile biten C string olmalı.
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn oryxis_skill_configure(grants_json: *const std::os::raw::c_char) {
+            if !grants_json.is_null() {
+                if let Ok(json) = std::ffi::CStr::from_ptr(grants_json).to_str() {
+                    let _ = $crate::grants::configure_json(json);
+                }
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub extern "C" fn oryxis_skill_alloc(len: u32) -> u32 {
+            $crate::wasm::alloc(len)
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub extern "C" fn oryxis_skill_describe() -> u64 {
+            $crate::wasm::pack(__oryxis_describe())
+        }
+
+        /// # Safety
+        /// Bölgeler host tarafından `oryxis_skill_alloc` ile ayrılmış olmalı.
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub unsafe extern "C" fn oryxis_skill_invoke(f_ptr: u32, f_len: u32, a_ptr: u32, a_len: u32) -> u64 {
+            let function = $crate::wasm::read(f_ptr, f_len);
+            let args_json = $crate::wasm::read(a_ptr, a_len);
+            $crate::wasm::pack($crate::invoke_str(&function, &args_json, $dispatch))
+        }
+
+        /// # Safety
+        /// `ptr`/`len` oryxis_skill_alloc/describe/invoke'tan gelmiş olmalı.
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub unsafe extern "C" fn oryxis_skill_free(ptr: u32, len: u32) {
+            $crate::wasm::free(ptr, len)
+        }
+
+        /// # Safety
+        /// Bölge host tarafından `oryxis_skill_alloc` ile ayrılmış olmalı.
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        pub unsafe extern "C" fn oryxis_skill_configure(ptr: u32, len: u32) {
+            let grants_json = $crate::wasm::read(ptr, len);
+            let _ = $crate::grants::configure_json(&grants_json);
+            $crate::wasm::free(ptr, len)
+        }
+
+        fn __oryxis_describe() -> String {
+            let functions = [
+                $( $crate::FunctionDesc { name: stringify!($fname), params: &[ $( stringify!($param) ),* ] } ),*
+            ];
+            $crate::describe_json($name, $version, &functions)
+        }
+    };
+}
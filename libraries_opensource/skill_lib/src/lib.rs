@@ -2,13 +2,89 @@ use serde::{Serialize, Deserialize};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
+pub mod search;
+#[cfg(feature = "semantic")]
+mod semantic;
+
+/// Metnin embedding vektörü (birim uzunlukta); `semantic` özelliği veya model yoksa Err.
+#[cfg(feature = "semantic")]
+pub use semantic::embed;
+
+#[cfg(not(feature = "semantic"))]
+pub fn embed(_text: &str) -> Result<Vec<f32>, String> {
+    Err("skill_lib was built without the `semantic` feature".to_string())
+}
+
+
+skill_abi::export_skill! {
+    name: "skill_lib",
+    version: env!("CARGO_PKG_VERSION"),
+    functions: [
+        get_skill_index(tags),
+        search_skills(query, top_k),
+        semantic_search(query, top_k),
+        get_yaml_content(file_path),
+        get_all_index(),
+        rebuild_skill_vectors(),
+    ],
+    dispatch: dispatch,
+}
+
+// Fonksiyonlar JSON string döndürüyor; {"error": ...} gelirse Err'e çevir
+fn json_result(raw: String) -> Result<serde_json::Value, String> {
+    match serde_json::from_str::<serde_json::Value>(&raw) {
+        Ok(serde_json::Value::Object(obj)) if obj.contains_key("error") => {
+            Err(obj["error"].as_str().map(|s| s.to_string()).unwrap_or_else(|| obj["error"].to_string()))
+        }
+        Ok(v) => Ok(v),
+        Err(e) => Err(format!("invalid result json: {}", e)),
+    }
+}
+
+fn dispatch(function: &str, args: &[serde_json::Value]) -> Result<serde_json::Value, String> {
+    let top_k = |i: usize| args.get(i).and_then(|v| v.as_u64()).map(|k| k as usize).unwrap_or(search::DEFAULT_TOP_K);
+    match function {
+        "get_skill_index" => {
+            let tags: Vec<String> = match args.first() {
+                Some(serde_json::Value::Array(a)) => a.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect(),
+                Some(v) => vec![v.as_str().map(|s| s.to_string()).unwrap_or_else(|| v.to_string())],
+                None => Vec::new(),
+            };
+            json_result(ranked_json(&tags.join(" "), search::DEFAULT_TOP_K))
+        }
+        "search_skills" => json_result(ranked_json(&skill_abi::arg_str(args, 0, "query")?, top_k(1))),
+        "semantic_search" => json_result(semantic_json(&skill_abi::arg_str(args, 0, "query")?, top_k(1))),
+        "get_yaml_content" => {
+            let path = skill_abi::arg_str(args, 0, "file_path")?;
+            skill_abi::grants::check_read(&path)?;
+            std::fs::read_to_string(path).map(serde_json::Value::String).map_err(|e| e.to_string())
+        }
+        "get_all_index" => json_result(load_index_source().unwrap_or_else(|e| e)),
+        "rebuild_skill_vectors" => json_result(rebuild_vectors_json()),
+        _ => Err(format!("unknown function `{}`", function)),
+    }
+}
 
 #[derive(Serialize, Deserialize)]
-struct SkillIndex {
-    name: String,
-    description: String,
-    tags: Vec<String>,
-    file: String,
+pub struct SkillIndex {
+    pub name: String,
+    pub description: String,
+    pub tags: Vec<String>,
+    pub file: String,
+    #[serde(default)]
+    pub abilities: Vec<String>,
+    #[serde(default)]
+    pub examples: Vec<String>,
+    // entrypoint, available vb. olduğu gibi geri döner
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize)]
+struct RankedSkill<'a> {
+    #[serde(flatten)]
+    skill: &'a SkillIndex,
+    score: f64,
 }
 
 
@@ -32,6 +108,33 @@ fn _get_skill_index(tags_json: *const c_char) -> String {
         Err(e) => return format!(r#"{{"error":"invalid tags json: {}"}}"#, e),
     };
 
+    ranked_json(&tags.join(" "), search::DEFAULT_TOP_K)
+}
+
+/// BM25 skorlu arama; `top_k` = 0 → varsayılan.
+///
+/// # Safety
+/// `query` null veya geçerli, NUL ile biten bir C string olmalı. Dönen string `free_string` ile bırakılır.
+#[no_mangle]
+pub unsafe extern "C" fn search_skills(query: *const c_char, top_k: u32) -> *mut c_char {
+    let result = unsafe {
+        if query.is_null() { "[]".to_string() }
+        else {
+            match CStr::from_ptr(query).to_str() {
+                Ok(q) => ranked_json(q, if top_k == 0 { search::DEFAULT_TOP_K } else { top_k as usize }),
+                Err(_) => "[]".to_string(),
+            }
+        }
+    };
+    CString::new(result).unwrap_or_default().into_raw()
+}
+
+fn load_index() -> Result<Vec<SkillIndex>, String> {
+    let json_str = load_index_source()?;
+    serde_json::from_str(&json_str).map_err(|e| format!(r#"{{"error":"parse error: {}"}}"#, e))
+}
+
+fn load_index_source() -> Result<String, String> {
     let json_str = match std::fs::read_to_string("memory/skills_index.json") {
         Ok(s) => s,
         Err(_) => {
@@ -42,28 +145,109 @@ fn _get_skill_index(tags_json: *const c_char) -> String {
             match exe_dir {
                 Some(d) => match std::fs::read_to_string(d.join("memory/skills_index.json")) {
                     Ok(s) => s,
-                    Err(e) => return format!(r#"{{"error":"{}"}}"#, e),
+                    Err(e) => return Err(format!(r#"{{"error":"{}"}}"#, e)),
                 },
-                None => return r#"{"error":"skills_index.json not found"}"#.to_string(),
+                None => return Err(r#"{"error":"skills_index.json not found"}"#.to_string()),
             }
         }
     };
+    Ok(json_str)
+}
 
-    let skills: Vec<SkillIndex> = match serde_json::from_str(&json_str) {
+/// En iyi eşleşme ilk sırada, her girdi `score` alanı taşır.
+fn ranked_json(query: &str, top_k: usize) -> String {
+    let skills = match load_index() {
         Ok(s) => s,
-        Err(e) => return format!(r#"{{"error":"parse error: {}"}}"#, e),
+        Err(e) => return e,
     };
 
-    let tags_lower: Vec<String> = tags.iter().map(|t| t.to_lowercase()).collect();
-    let filtered: Vec<&SkillIndex> = skills.iter().filter(|s| {
-        tags_lower.iter().any(|tag| {
-            s.tags.iter().any(|t| t.to_lowercase().contains(tag.as_str()))
-                || s.name.to_lowercase().contains(tag.as_str())
-                || s.description.to_lowercase().contains(tag.as_str())
-        })
-    }).collect();
+    let ranked: Vec<RankedSkill> = search::rank(&skills, query, top_k)
+        .into_iter()
+        .map(|(skill, score)| RankedSkill { skill, score: (score * 1000.0).round() / 1000.0 })
+        .collect();
+
+    serde_json::to_string_pretty(&ranked).unwrap_or("[]".to_string())
+}
+
+#[cfg(feature = "semantic")]
+#[derive(Serialize)]
+struct SemanticMatch<'a> {
+    #[serde(flatten)]
+    skill: &'a SkillIndex,
+    score: f64,
+    #[serde(rename = "match")]
+    matched: String,
+}
+
+/// Embedding tabanlı arama; tag örtüşmesi gerekmez ("play some music" -> cmd_lib).
+///
+/// # Safety
+/// `query` null veya geçerli, NUL ile biten bir C string olmalı. Dönen string `free_string` ile bırakılır.
+#[no_mangle]
+pub unsafe extern "C" fn semantic_search(query: *const c_char, top_k: u32) -> *mut c_char {
+    let result = unsafe {
+        if query.is_null() { "[]".to_string() }
+        else {
+            match CStr::from_ptr(query).to_str() {
+                Ok(q) => semantic_json(q, if top_k == 0 { search::DEFAULT_TOP_K } else { top_k as usize }),
+                Err(_) => "[]".to_string(),
+            }
+        }
+    };
+    CString::new(result).unwrap_or_default().into_raw()
+}
+
+#[cfg(feature = "semantic")]
+fn semantic_json(query: &str, top_k: usize) -> String {
+    let source = match load_index_source() {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let skills = match load_index() {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+
+    match semantic::search(&skills, &source, query, top_k) {
+        Ok(found) => {
+            let matches: Vec<SemanticMatch> = found
+                .into_iter()
+                .map(|(skill, score, matched)| SemanticMatch {
+                    skill,
+                    score: (score as f64 * 1000.0).round() / 1000.0,
+                    matched,
+                })
+                .collect();
+            serde_json::to_string_pretty(&matches).unwrap_or("[]".to_string())
+        }
+        Err(e) => serde_json::json!({ "error": e }).to_string(),
+    }
+}
+
+#[cfg(not(feature = "semantic"))]
+fn semantic_json(_query: &str, _top_k: usize) -> String {
+    r#"{"error":"skill_lib was built without the `semantic` feature"}"#.to_string()
+}
+
+/// memory/skills_vectors.json'ı yeniden üretir.
+#[no_mangle]
+pub extern "C" fn rebuild_skill_vectors() -> *mut c_char {
+    CString::new(rebuild_vectors_json()).unwrap_or_default().into_raw()
+}
+
+fn rebuild_vectors_json() -> String {
+    #[cfg(feature = "semantic")]
+    let result = match (load_index_source(), load_index()) {
+        (Ok(source), Ok(skills)) => match semantic::rebuild(&skills, &source) {
+            Ok(n) => format!(r#"{{"status":"success","vectors":{}}}"#, n),
+            Err(e) => serde_json::json!({ "error": e }).to_string(),
+        },
+        (Err(e), _) | (_, Err(e)) => e,
+    };
+    #[cfg(not(feature = "semantic"))]
+    let result = r#"{"error":"skill_lib was built without the `semantic` feature"}"#.to_string();
 
-    serde_json::to_string_pretty(&filtered).unwrap_or("[]".to_string())
+    result
 }
 
 #[no_mangle]
@@ -71,7 +255,10 @@ pub extern "C" fn get_yaml_content(path: *const c_char) -> *mut c_char {
     let result = unsafe {
         if path.is_null() { return CString::new("").unwrap().into_raw(); }
         match CStr::from_ptr(path).to_str() {
-            Ok(p) => std::fs::read_to_string(p).unwrap_or_else(|e| format!(r#"{{"error":"{}"}}"#, e)),
+            Ok(p) => match skill_abi::grants::check_read(p) {
+                Ok(()) => std::fs::read_to_string(p).unwrap_or_else(|e| serde_json::json!({ "error": e.to_string() }).to_string()),
+                Err(e) => serde_json::json!({ "error": e }).to_string(),
+            },
             Err(_) => String::new(),
         }
     };
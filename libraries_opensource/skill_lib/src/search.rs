@@ -0,0 +1,147 @@
+// BM25F skill araması: alan ağırlıklı, İngilizce + Türkçe stemming, skorlu top-k
+
+use rust_stemmers::{Algorithm, Stemmer};
+use std::collections::{HashMap, HashSet};
+
+use crate::SkillIndex;
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+// name > tags = abilities > examples > description
+const FIELD_WEIGHTS: [f64; 5] = [3.0, 2.0, 2.0, 1.5, 1.0];
+
+pub const DEFAULT_TOP_K: usize = 5;
+
+/// Küçük harf, alfanümerik olmayan karakterlerden böl (snake_case de bölünür),
+/// her kelimeyi hem İngilizce hem Türkçe kökle.
+pub fn analyze(text: &str) -> Vec<String> {
+    let en = Stemmer::create(Algorithm::English);
+    let tr = Stemmer::create(Algorithm::Turkish);
+
+    let mut terms = Vec::new();
+    for word in text.to_lowercase().split(|c: char| !c.is_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        let en_stem = en.stem(word).to_string();
+        let tr_stem = tr.stem(word).to_string();
+        if tr_stem != en_stem {
+            terms.push(tr_stem);
+        }
+        terms.push(en_stem);
+    }
+    terms
+}
+
+fn fields(skill: &SkillIndex) -> [Vec<String>; 5] {
+    [
+        analyze(&skill.name),
+        analyze(&skill.tags.join(" ")),
+        analyze(&skill.abilities.join(" ")),
+        analyze(&skill.examples.join(" ")),
+        analyze(&skill.description),
+    ]
+}
+
+fn idf(n: f64, df: usize) -> f64 {
+    (1.0 + (n - df as f64 + 0.5) / (df as f64 + 0.5)).ln()
+}
+
+/// BM25F: her doküman N alan, alan ağırlıklı; sorgu terimi geçmeyen dokümanın skoru 0.
+fn bm25<const N: usize>(docs: &[[Vec<String>; N]], weights: &[f64; N], query_terms: &HashSet<String>) -> Vec<f64> {
+    let mut avg_len = [0.0f64; N];
+    for doc in docs {
+        for (i, field) in doc.iter().enumerate() {
+            avg_len[i] += field.len() as f64;
+        }
+    }
+    for len in avg_len.iter_mut() {
+        *len = (*len / docs.len().max(1) as f64).max(1.0);
+    }
+
+    // Document frequency: terimin herhangi bir alanda geçtiği doküman sayısı
+    let mut df: HashMap<&str, usize> = HashMap::new();
+    for doc in docs {
+        let unique: HashSet<&str> = doc.iter().flatten().map(|t| t.as_str()).collect();
+        for term in unique {
+            *df.entry(term).or_default() += 1;
+        }
+    }
+
+    let n = docs.len() as f64;
+    docs.iter()
+        .map(|doc| {
+            let mut score = 0.0;
+            for term in query_terms {
+                let Some(&term_df) = df.get(term.as_str()) else { continue };
+
+                // Alan bazında normalize edilmiş ağırlıklı tf
+                let mut tf = 0.0;
+                for (i, field) in doc.iter().enumerate() {
+                    let count = field.iter().filter(|t| *t == term).count() as f64;
+                    if count > 0.0 {
+                        let norm = 1.0 - B + B * field.len() as f64 / avg_len[i];
+                        tf += weights[i] * count / norm;
+                    }
+                }
+                if tf > 0.0 {
+                    score += idf(n, term_df) * tf / (K1 + tf);
+                }
+            }
+            score
+        })
+        .collect()
+}
+
+/// Skill'leri sorguya göre skorlar; skoru 0 olanlar atlanır, en iyi `top_k` döner.
+pub fn rank<'a>(skills: &'a [SkillIndex], query: &str, top_k: usize) -> Vec<(&'a SkillIndex, f64)> {
+    let query_terms: HashSet<String> = analyze(query).into_iter().collect();
+    if query_terms.is_empty() || skills.is_empty() {
+        return Vec::new();
+    }
+
+    let docs: Vec<[Vec<String>; 5]> = skills.iter().map(fields).collect();
+    let mut scored: Vec<(&SkillIndex, f64)> = skills
+        .iter()
+        .zip(bm25(&docs, &FIELD_WEIGHTS, &query_terms))
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(top_k);
+    scored
+}
+
+/// Düz metinler için aynı BM25 (hafıza kayıtları gibi): (index, skor), skoru 0 olanlar atlanır.
+pub fn rank_texts(texts: &[String], query: &str, top_k: usize) -> Vec<(usize, f64)> {
+    let query_terms: HashSet<String> = analyze(query).into_iter().collect();
+    if query_terms.is_empty() || texts.is_empty() {
+        return Vec::new();
+    }
+
+    let docs: Vec<[Vec<String>; 1]> = texts.iter().map(|t| [analyze(t)]).collect();
+    let mut scored: Vec<(usize, f64)> = bm25(&docs, &[1.0], &query_terms)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(top_k);
+    scored
+}
+
+/// Sorgunun bu metinlerde alabileceği en yüksek skor (geçen her terim için tf → ∞, yani idf
+/// toplamı). rank_texts skorlarını kayıt sayısından bağımsız 0..1'e ölçeklemek için; 0 → eşleşme yok.
+pub fn ceiling_texts(texts: &[String], query: &str) -> f64 {
+    let query_terms: HashSet<String> = analyze(query).into_iter().collect();
+    let docs: Vec<HashSet<String>> = texts.iter().map(|t| analyze(t).into_iter().collect()).collect();
+    let n = docs.len() as f64;
+    query_terms
+        .iter()
+        .map(|term| docs.iter().filter(|doc| doc.contains(term)).count())
+        .filter(|df| *df > 0)
+        .map(|df| idf(n, df))
+        .sum()
+}
@@ -0,0 +1,227 @@
+// Offline semantik skill araması: yerel ONNX embedding modeli (CPU, tract).
+// Model: memory/models/embedding/{model.onnx, tokenizer.json} (örn. all-MiniLM-L6-v2)
+// Vektörler memory/skills_vectors.json'a yazılır, index veya model değişince yeniden üretilir.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use tokenizers::{Tokenizer, TruncationParams};
+use tract_onnx::prelude::*;
+
+use crate::SkillIndex;
+
+const MODEL_DIR: &str = "memory/models/embedding";
+const VECTORS_PATH: &str = "memory/skills_vectors.json";
+const MAX_TOKENS: usize = 256;
+
+#[derive(Serialize, Deserialize)]
+struct VectorEntry {
+    skill: String,
+    text: String,
+    vector: Vec<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VectorIndex {
+    source_hash: u64,
+    model_hash: u64,
+    entries: Vec<VectorEntry>,
+}
+
+struct Embedder {
+    tokenizer: Tokenizer,
+    model: TypedRunnableModel<TypedModel>,
+    input_names: Vec<String>,
+}
+
+static EMBEDDER: OnceLock<Result<Mutex<Embedder>, String>> = OnceLock::new();
+
+// FNV-1a: index/model değişti mi kontrolü için, Rust sürümünden bağımsız
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325u64, |h, b| (h ^ *b as u64).wrapping_mul(0x100000001b3))
+}
+
+fn model_hash() -> u64 {
+    let meta = std::fs::metadata(Path::new(MODEL_DIR).join("model.onnx"));
+    let (len, modified) = match meta {
+        Ok(m) => (
+            m.len(),
+            m.modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        ),
+        Err(_) => (0, 0),
+    };
+    fnv1a(format!("{}:{}", len, modified).as_bytes())
+}
+
+impl Embedder {
+    fn load() -> Result<Self, String> {
+        let dir = Path::new(MODEL_DIR);
+        let mut tokenizer = Tokenizer::from_file(dir.join("tokenizer.json"))
+            .map_err(|e| format!("tokenizer.json: {}", e))?;
+        tokenizer
+            .with_truncation(Some(TruncationParams { max_length: MAX_TOKENS, ..Default::default() }))
+            .map_err(|e| e.to_string())?;
+        tokenizer.with_padding(None);
+
+        let model = tract_onnx::onnx()
+            .model_for_path(dir.join("model.onnx"))
+            .and_then(|m| m.into_optimized())
+            .map_err(|e| format!("model.onnx: {}", e))?;
+        let input_names = model
+            .input_outlets()
+            .map_err(|e| e.to_string())?
+            .iter()
+            .map(|o| model.node(o.node).name.clone())
+            .collect();
+        let model = model.into_runnable().map_err(|e| e.to_string())?;
+
+        Ok(Embedder { tokenizer, model, input_names })
+    }
+
+    /// Mean pooling + L2 normalize
+    fn embed(&self, text: &str) -> Result<Vec<f32>, String> {
+        let enc = self.tokenizer.encode(text, true).map_err(|e| e.to_string())?;
+        let len = enc.get_ids().len();
+        let ids: Vec<i64> = enc.get_ids().iter().map(|&x| x as i64).collect();
+        let mask: Vec<i64> = enc.get_attention_mask().iter().map(|&x| x as i64).collect();
+        let types: Vec<i64> = enc.get_type_ids().iter().map(|&x| x as i64).collect();
+
+        let mut inputs: TVec<TValue> = TVec::new();
+        for name in &self.input_names {
+            let data = if name.contains("mask") {
+                &mask
+            } else if name.contains("type") {
+                &types
+            } else {
+                &ids
+            };
+            let tensor: Tensor = tract_ndarray::Array2::from_shape_vec((1, len), data.clone())
+                .map_err(|e| e.to_string())?
+                .into();
+            inputs.push(tensor.into());
+        }
+
+        let outputs = self.model.run(inputs).map_err(|e| e.to_string())?;
+        let hidden = outputs[0].to_array_view::<f32>().map_err(|e| e.to_string())?;
+
+        let mut vector = match hidden.ndim() {
+            // [1, dim] — model zaten sentence embedding veriyor
+            2 => hidden.iter().copied().collect::<Vec<f32>>(),
+            // [1, seq, dim] — token embedding'lerinin maskeli ortalaması
+            3 => {
+                let dim = hidden.shape()[2];
+                let mut sum = vec![0f32; dim];
+                let mut count = 0f32;
+                for (t, m) in mask.iter().enumerate() {
+                    if *m == 0 {
+                        continue;
+                    }
+                    for (d, s) in sum.iter_mut().enumerate() {
+                        *s += hidden[[0, t, d]];
+                    }
+                    count += 1.0;
+                }
+                sum.iter().map(|s| s / count.max(1.0)).collect()
+            }
+            n => return Err(format!("unexpected model output rank {}", n)),
+        };
+
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt().max(1e-12);
+        vector.iter_mut().for_each(|v| *v /= norm);
+        Ok(vector)
+    }
+}
+
+fn with_embedder<T>(f: impl FnOnce(&Embedder) -> Result<T, String>) -> Result<T, String> {
+    match EMBEDDER.get_or_init(|| Embedder::load().map(Mutex::new)) {
+        Ok(embedder) => {
+            let embedder = embedder.lock().map_err(|e| e.to_string())?;
+            f(&embedder)
+        }
+        Err(e) => Err(format!("embedding model unavailable ({}): {}", MODEL_DIR, e)),
+    }
+}
+
+/// Tek metnin (L2 normalize) vektörü; hafıza araması da aynı modeli kullanır.
+pub fn embed(text: &str) -> Result<Vec<f32>, String> {
+    with_embedder(|embedder| embedder.embed(text))
+}
+
+/// Skill başına gömülecek metinler: açıklama, yetenekler, örnek başlıkları
+fn documents(skill: &SkillIndex) -> Vec<String> {
+    let mut docs = vec![format!("{}: {}", skill.name.replace('_', " "), skill.description)];
+    docs.extend(skill.abilities.iter().map(|a| a.replace('_', " ")));
+    docs.extend(skill.examples.iter().cloned());
+    docs
+}
+
+fn build(skills: &[SkillIndex], source_hash: u64) -> Result<VectorIndex, String> {
+    let mut entries = Vec::new();
+    with_embedder(|embedder| {
+        for skill in skills {
+            for text in documents(skill) {
+                let vector = embedder.embed(&text)?;
+                entries.push(VectorEntry { skill: skill.name.clone(), text, vector });
+            }
+        }
+        Ok(())
+    })?;
+
+    let index = VectorIndex { source_hash, model_hash: model_hash(), entries };
+    if let Ok(json) = serde_json::to_string(&index) {
+        let _ = std::fs::write(VECTORS_PATH, json);
+    }
+    Ok(index)
+}
+
+/// Kayıtlı vektör index'i güncelse onu, değilse yeniden üretileni döner.
+fn load_or_build(skills: &[SkillIndex], source: &str) -> Result<VectorIndex, String> {
+    let source_hash = fnv1a(source.as_bytes());
+    if let Ok(saved) = std::fs::read_to_string(VECTORS_PATH) {
+        if let Ok(index) = serde_json::from_str::<VectorIndex>(&saved) {
+            if index.source_hash == source_hash && index.model_hash == model_hash() {
+                return Ok(index);
+            }
+        }
+    }
+    build(skills, source_hash)
+}
+
+/// Vektör index'ini zorla yeniden üretir, girdi sayısını döner.
+pub fn rebuild(skills: &[SkillIndex], source: &str) -> Result<usize, String> {
+    build(skills, fnv1a(source.as_bytes())).map(|index| index.entries.len())
+}
+
+/// Skill skoru = sorgu ile en yakın dokümanın kosinüs benzerliği.
+/// En iyi eşleşen metin de döner (örn. "Open Spotify").
+pub fn search<'a>(
+    skills: &'a [SkillIndex],
+    source: &str,
+    query: &str,
+    top_k: usize,
+) -> Result<Vec<(&'a SkillIndex, f32, String)>, String> {
+    let index = load_or_build(skills, source)?;
+    let query_vec = with_embedder(|embedder| embedder.embed(query))?;
+
+    let mut scored: Vec<(&SkillIndex, f32, String)> = Vec::new();
+    for skill in skills {
+        let best = index
+            .entries
+            .iter()
+            .filter(|e| e.skill == skill.name)
+            .map(|e| (e.vector.iter().zip(&query_vec).map(|(a, b)| a * b).sum::<f32>(), &e.text))
+            .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        // Kosinüs ≤ 0: anlamca ilgisiz, BM25'teki gibi hiç listelenmez
+        if let Some((score, text)) = best.filter(|(score, _)| *score > 0.0) {
+            scored.push((skill, score, text.clone()));
+        }
+    }
+
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    scored.truncate(top_k);
+    Ok(scored)
+}